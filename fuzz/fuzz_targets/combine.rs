@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use vsss_rs::*;
+
+type K256Share = DefaultShare<IdentifierPrimeField<k256::Scalar>, ValuePrimeField<k256::Scalar>>;
+
+fuzz_target!(|shares: Vec<K256Share>| {
+    let _ = shares.combine();
+});
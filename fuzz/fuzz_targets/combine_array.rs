@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use vsss_rs::Gf256;
+
+fuzz_target!(|shares: Vec<Vec<u8>>| {
+    let _ = Gf256::combine_array(&shares);
+});
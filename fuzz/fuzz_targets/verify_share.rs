@@ -0,0 +1,19 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use vsss_rs::*;
+
+type K256Share = DefaultShare<IdentifierPrimeField<k256::Scalar>, ValuePrimeField<k256::Scalar>>;
+type K256Verifier = ValueGroup<k256::ProjectivePoint>;
+
+fuzz_target!(|input: (
+    Vec<K256Share>,
+    VecFeldmanVerifierSet<K256Share, K256Verifier>,
+    VecPedersenVerifierSet<K256Share, K256Verifier>,
+)| {
+    let (shares, feldman, pedersen) = input;
+    for share in &shares {
+        let _ = feldman.verify_share(share);
+        let _ = pedersen.verify_share_and_blinder(share, share);
+    }
+});
@@ -0,0 +1,88 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+//! Additive n-of-n secret sharing.
+//!
+//! Every share is a random value except the last which is chosen
+//! so the values sum to the secret. All shares are required to
+//! reconstruct the secret and no polynomial is involved.
+use crate::*;
+use rand_core::{CryptoRng, RngCore};
+
+/// Split `secret` into `limit` additive shares that sum to it.
+pub fn split<S: Share>(
+    limit: usize,
+    secret: &S::Value,
+    rng: impl RngCore + CryptoRng,
+) -> VsssResult<Vec<S>> {
+    let generator = ParticipantIdGeneratorType::<S::Identifier>::default();
+    split_with_participant_generator(limit, secret, rng, &[generator])
+}
+
+/// Split `secret` into `limit` additive shares that sum to it
+/// using identifiers from `participant_generators`.
+pub fn split_with_participant_generator<S: Share>(
+    limit: usize,
+    secret: &S::Value,
+    mut rng: impl RngCore + CryptoRng,
+    participant_generators: &[ParticipantIdGeneratorType<S::Identifier>],
+) -> VsssResult<Vec<S>> {
+    if limit < 2 {
+        return Err(Error::SharingMinThreshold);
+    }
+    let collection = ParticipantIdGeneratorCollection::from(participant_generators);
+    let ids = collection.iter().take(limit).collect::<Vec<_>>();
+    if ids.len() < limit {
+        return Err(Error::NotEnoughShareIdentifiers);
+    }
+    shamir::check_identifiers(&ids)?;
+
+    let mut last = secret.clone();
+    let mut shares = Vec::with_capacity(limit);
+    for id in &ids[..limit - 1] {
+        let value = S::Value::random(&mut rng);
+        *last.as_mut() -= value.as_ref();
+        shares.push(S::with_identifier_and_value(id.clone(), value));
+    }
+    shares.push(S::with_identifier_and_value(ids[limit - 1].clone(), last));
+    Ok(shares)
+}
+
+/// Reconstruct the secret by summing the values of all the additive shares.
+pub fn combine<S: Share>(shares: &[S]) -> VsssResult<S::Value> {
+    if shares.len() < 2 {
+        return Err(Error::SharingMinThreshold);
+    }
+    let ids = shares
+        .iter()
+        .map(|s| s.identifier().clone())
+        .collect::<Vec<_>>();
+    shamir::check_identifiers(&ids)?;
+    let mut secret = S::Value::zero();
+    for share in shares {
+        *secret.as_mut() += share.value().as_ref();
+    }
+    Ok(secret)
+}
+
+/// Reconstruct a master secret that was split into sub-secrets which were
+/// each shared independently.
+///
+/// Each set in `sub_share_sets` is combined into its sub-secret then the
+/// sub-secrets are folded into the master with `combine_fn`, e.g.
+/// field addition for sub-secrets created by [`split`] or XOR for bytes.
+pub fn combine_sharded<S, F>(sub_share_sets: &[&[S]], mut combine_fn: F) -> VsssResult<S::Value>
+where
+    S: Share,
+    F: FnMut(S::Value, S::Value) -> S::Value,
+{
+    let (first, rest) = sub_share_sets
+        .split_first()
+        .ok_or(Error::InvalidSizeRequest)?;
+    let mut master = first.combine()?;
+    for shares in rest {
+        master = combine_fn(master, shares.combine()?);
+    }
+    Ok(master)
+}
@@ -0,0 +1,279 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+//! Asmuth-Bloom secret sharing for integer secrets
+//! using the Chinese Remainder Theorem.
+//!
+//! Each participant is identified by a modulus `m_i` and holds
+//! `y mod m_i` where `y = secret + alpha * m_0` for a random `alpha`
+//! and a secret modulus `m_0`. Any `threshold` residues recover `y`
+//! by the Chinese Remainder Theorem and thus `secret = y mod m_0`.
+//!
+//! The moduli must satisfy the Asmuth-Bloom condition that the product of
+//! the `threshold` smallest moduli exceeds `m_0` times the product of the
+//! `threshold - 1` largest moduli, otherwise fewer than `threshold`
+//! shares leak information about the secret.
+//!
+//! This scheme is NOT constant time. Masking the secret in [`split_secret`]
+//! and the Chinese Remainder Theorem in [`combine`] use arbitrary precision
+//! [`BigUint`] arithmetic whose timing depends on the secret, the random
+//! mask and the residues. Only use it where timing side channels on the
+//! dealer and combiner are not a concern.
+use crate::*;
+use crypto_bigint::{ArrayEncoding, ByteArray, Uint};
+use num::{bigint::BigUint, Integer, One, Zero};
+use rand_core::{CryptoRng, RngCore};
+
+/// An Asmuth-Bloom share of the participant's modulus and residue
+pub type AsmuthBloomShare<const LIMBS: usize> = (IdentifierUint<LIMBS>, ValueUint<LIMBS>);
+
+/// The default secret modulus `m_0 = 2^(BITS / 2)`.
+///
+/// Secrets split with [`split_secret`] must be smaller than this
+/// so a [`Uint<8>`] holds secrets of up to 256 bits.
+pub fn secret_modulus<const LIMBS: usize>() -> Uint<LIMBS>
+where
+    Uint<LIMBS>: ArrayEncoding,
+{
+    Uint::<LIMBS>::ONE.shl_vartime(Uint::<LIMBS>::BITS / 2)
+}
+
+/// The default participant moduli used by [`split_secret`].
+///
+/// These are the `limit` largest odd numbers that fit in [`Uint<LIMBS>`]
+/// and are pairwise coprime.
+pub fn default_moduli<const LIMBS: usize>(limit: usize) -> Vec<Uint<LIMBS>>
+where
+    Uint<LIMBS>: ArrayEncoding,
+{
+    let mut moduli: Vec<BigUint> = Vec::with_capacity(limit);
+    let mut candidate = to_biguint(&Uint::<LIMBS>::MAX);
+    let two = BigUint::from(2u8);
+    while moduli.len() < limit && candidate > two {
+        if moduli.iter().all(|m| m.gcd(&candidate).is_one()) {
+            moduli.push(candidate.clone());
+        }
+        candidate -= &two;
+    }
+    moduli.iter().map(from_biguint).collect()
+}
+
+/// Split an integer secret into `limit` shares where any `threshold`
+/// recover it, using [`secret_modulus`] and [`default_moduli`].
+///
+/// Returns [`Error::InvalidSecret`] if `secret` is not smaller than [`secret_modulus`].
+pub fn split_secret<const LIMBS: usize>(
+    threshold: usize,
+    limit: usize,
+    secret: &Uint<LIMBS>,
+    rng: impl RngCore + CryptoRng,
+) -> VsssResult<Vec<AsmuthBloomShare<LIMBS>>>
+where
+    Uint<LIMBS>: ArrayEncoding,
+{
+    check_params(threshold, limit)?;
+    split_secret_with_moduli(
+        threshold,
+        &secret_modulus::<LIMBS>(),
+        &default_moduli::<LIMBS>(limit),
+        secret,
+        rng,
+    )
+}
+
+/// Split an integer secret into one share per modulus in `moduli`
+/// where any `threshold` recover it.
+///
+/// Returns [`Error::InvalidSecret`] if `secret` is not smaller than `secret_modulus`,
+/// [`Error::SharingDuplicateIdentifier`] if a modulus is repeated, and
+/// [`Error::InvalidSizeRequest`] if the moduli are not pairwise coprime
+/// or do not satisfy the Asmuth-Bloom condition.
+pub fn split_secret_with_moduli<const LIMBS: usize>(
+    threshold: usize,
+    secret_modulus: &Uint<LIMBS>,
+    moduli: &[Uint<LIMBS>],
+    secret: &Uint<LIMBS>,
+    mut rng: impl RngCore + CryptoRng,
+) -> VsssResult<Vec<AsmuthBloomShare<LIMBS>>>
+where
+    Uint<LIMBS>: ArrayEncoding,
+{
+    check_params(threshold, moduli.len())?;
+    let m_0 = to_biguint(secret_modulus);
+    let secret = to_biguint(secret);
+    if secret >= m_0 {
+        return Err(Error::InvalidSecret);
+    }
+    let mut sorted = moduli.iter().map(to_biguint).collect::<Vec<_>>();
+    sorted.sort();
+    if sorted.windows(2).any(|w| w[0] == w[1]) {
+        return Err(Error::SharingDuplicateIdentifier);
+    }
+    if sorted[0] <= BigUint::one() {
+        return Err(Error::InvalidSizeRequest);
+    }
+    check_coprime(&m_0, &sorted)?;
+
+    // prod(m_1..m_t) > m_0 * prod(m_{n-t+2}..m_n)
+    let smallest: BigUint = sorted[..threshold].iter().product();
+    let largest: BigUint = sorted[sorted.len() - (threshold - 1)..].iter().product();
+    if smallest <= &m_0 * largest {
+        return Err(Error::InvalidSizeRequest);
+    }
+
+    // y = secret + alpha * m_0 < prod(m_1..m_t)
+    let alpha_bound = (&smallest - BigUint::one() - &secret) / &m_0 + BigUint::one();
+    let mut bytes = vec![0u8; alpha_bound.bits().div_ceil(8) as usize + 16];
+    rng.fill_bytes(&mut bytes);
+    let alpha = BigUint::from_bytes_be(&bytes) % alpha_bound;
+    let y = secret + alpha * m_0;
+
+    Ok(moduli
+        .iter()
+        .map(|m| {
+            let residue = &y % to_biguint(m);
+            (
+                IdentifierUint::from(Saturating(*m)),
+                IdentifierUint::from(Saturating(from_biguint(&residue))),
+            )
+        })
+        .collect())
+}
+
+/// Reconstruct a secret split with [`split_secret`].
+pub fn combine<const LIMBS: usize>(shares: &[AsmuthBloomShare<LIMBS>]) -> VsssResult<Uint<LIMBS>>
+where
+    Uint<LIMBS>: ArrayEncoding,
+{
+    combine_with_modulus(&secret_modulus::<LIMBS>(), shares)
+}
+
+/// Reconstruct a secret split with [`split_secret_with_moduli`] using `secret_modulus`.
+///
+/// Returns [`Error::InvalidShare`] if the moduli of the shares are not pairwise coprime.
+pub fn combine_with_modulus<const LIMBS: usize>(
+    secret_modulus: &Uint<LIMBS>,
+    shares: &[AsmuthBloomShare<LIMBS>],
+) -> VsssResult<Uint<LIMBS>>
+where
+    Uint<LIMBS>: ArrayEncoding,
+{
+    if shares.len() < 2 {
+        return Err(Error::SharingMinThreshold);
+    }
+    let m_0 = to_biguint(secret_modulus);
+    if m_0.is_zero() {
+        return Err(Error::InvalidSizeRequest);
+    }
+    for (i, (m_i, _)) in shares.iter().enumerate() {
+        if m_i.0 .0 <= Uint::<LIMBS>::ONE {
+            return Err(Error::SharingInvalidIdentifier);
+        }
+        if shares[i + 1..].iter().any(|(m_j, _)| m_j == m_i) {
+            return Err(Error::SharingDuplicateIdentifier);
+        }
+    }
+
+    let n: BigUint = shares.iter().map(|(m, _)| to_biguint(&m.0 .0)).product();
+    let mut y = BigUint::zero();
+    for (m_i, r_i) in shares {
+        let m_i = to_biguint(&m_i.0 .0);
+        let n_i = &n / &m_i;
+        let inv = (&n_i % &m_i).modinv(&m_i).ok_or(Error::InvalidShare)?;
+        y += to_biguint(&r_i.0 .0) * inv % &m_i * n_i;
+    }
+    Ok(from_biguint(&(y % n % m_0)))
+}
+
+fn check_coprime(m_0: &BigUint, moduli: &[BigUint]) -> VsssResult<()> {
+    for (i, m_i) in moduli.iter().enumerate() {
+        if !m_i.gcd(m_0).is_one() || moduli[i + 1..].iter().any(|m_j| !m_i.gcd(m_j).is_one()) {
+            return Err(Error::InvalidSizeRequest);
+        }
+    }
+    Ok(())
+}
+
+fn to_biguint<const LIMBS: usize>(value: &Uint<LIMBS>) -> BigUint
+where
+    Uint<LIMBS>: ArrayEncoding,
+{
+    BigUint::from_bytes_be(&value.to_be_byte_array())
+}
+
+fn from_biguint<const LIMBS: usize>(value: &BigUint) -> Uint<LIMBS>
+where
+    Uint<LIMBS>: ArrayEncoding,
+{
+    let bytes = value.to_bytes_be();
+    let mut array = ByteArray::<Uint<LIMBS>>::default();
+    let offset = array.len() - bytes.len();
+    array[offset..].copy_from_slice(&bytes);
+    Uint::<LIMBS>::from_be_byte_array(array)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto_bigint::{Random, U512};
+    use rand_core::SeedableRng;
+
+    #[test]
+    fn split_combine_256_bit_secret() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([7u8; 32]);
+        let secret = U512::from_be_hex(concat!(
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "ffeeddccbbaa99887766554433221100ffeeddccbbaa99887766554433221100"
+        ));
+        let shares = split_secret(3, 5, &secret, &mut rng).unwrap();
+        assert_eq!(shares.len(), 5);
+        assert!(shares.iter().all(|(_, r)| r.0 .0 != secret));
+
+        for i in 0..5 {
+            for j in i + 1..5 {
+                for k in j + 1..5 {
+                    let subset = [shares[i], shares[j], shares[k]];
+                    assert_eq!(combine(&subset).unwrap(), secret);
+                }
+            }
+        }
+        assert_eq!(combine(&shares).unwrap(), secret);
+
+        let secret = U512::random(&mut rng) >> 256;
+        let shares = split_secret(3, 5, &secret, &mut rng).unwrap();
+        assert_eq!(combine(&shares[2..]).unwrap(), secret);
+
+        let too_large = secret_modulus::<8>();
+        assert_eq!(
+            split_secret(3, 5, &too_large, &mut rng).unwrap_err(),
+            Error::InvalidSecret
+        );
+    }
+
+    #[test]
+    fn gap_condition() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([8u8; 32]);
+        let m_0 = U512::from_u8(5);
+        let secret = U512::from_u8(3);
+
+        // 11 * 13 * 17 > 5 * 19 * 23
+        let moduli = [11u8, 13, 17, 19, 23].map(U512::from_u8);
+        let shares = split_secret_with_moduli(3, &m_0, &moduli, &secret, &mut rng).unwrap();
+        assert_eq!(combine_with_modulus(&m_0, &shares[1..4]).unwrap(), secret);
+
+        // 7 * 11 * 13 < 5 * 29 * 31
+        let moduli = [7u8, 11, 13, 29, 31].map(U512::from_u8);
+        assert_eq!(
+            split_secret_with_moduli(3, &m_0, &moduli, &secret, &mut rng).unwrap_err(),
+            Error::InvalidSizeRequest
+        );
+
+        // Not pairwise coprime
+        let moduli = [11u8, 13, 17, 19, 22].map(U512::from_u8);
+        assert_eq!(
+            split_secret_with_moduli(3, &m_0, &moduli, &secret, &mut rng).unwrap_err(),
+            Error::InvalidSizeRequest
+        );
+    }
+}
@@ -31,6 +31,7 @@ use elliptic_curve::{
 #[cfg(feature = "bigint")]
 use elliptic_curve::{ops::Reduce, scalar::FromUintUnchecked};
 
+use crate::{ReadableShareSet, Share, ValuePrimeField, VsssResult};
 use rand_core::RngCore;
 #[cfg(feature = "serde")]
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
@@ -684,6 +685,18 @@ impl ConstantTimeEq for WrappedEdwards {
     }
 }
 
+/// Combine shares of a curve25519 scalar and return the
+/// canonical bytes of the reconstructed scalar.
+///
+/// The bytes can be used directly to create an x25519 `StaticSecret`
+/// or an ed25519 `SigningKey`.
+pub fn combine_to_scalar_bytes<S>(shares: &[S]) -> VsssResult<[u8; 32]>
+where
+    S: Share<Value = ValuePrimeField<WrappedScalar>>,
+{
+    shares.combine().map(|secret| secret.0 .0.to_bytes())
+}
+
 /// Wraps a curve25519 scalar
 #[derive(Copy, Clone, Debug, Eq, Default)]
 pub struct WrappedScalar(pub Scalar);
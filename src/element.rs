@@ -3,22 +3,36 @@
 #[cfg(any(feature = "alloc", feature = "std"))]
 mod biguint;
 mod group_element;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod label;
+mod nonzero_scalar;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod point_identifier;
 mod prime_field;
 #[cfg(feature = "primitive")]
 mod primitive;
 #[cfg(feature = "bigint")]
 mod residue;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod tuple;
 #[cfg(feature = "bigint")]
 mod uint;
 
 #[cfg(any(feature = "alloc", feature = "std"))]
 pub use biguint::*;
 pub use group_element::*;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use label::*;
+pub use nonzero_scalar::*;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use point_identifier::*;
 pub use prime_field::*;
 #[cfg(feature = "primitive")]
 pub use primitive::*;
 #[cfg(feature = "bigint")]
 pub use residue::*;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use tuple::*;
 #[cfg(feature = "bigint")]
 pub use uint::*;
 
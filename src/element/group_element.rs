@@ -1,12 +1,18 @@
 use crate::*;
 use core::{
     fmt::{self, Display, Formatter},
+    hash::{Hash, Hasher},
     ops::{Add, AddAssign, Deref, DerefMut, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 #[cfg(feature = "bigint")]
 use crypto_bigint::{modular::constant_mod::ResidueParams, ArrayEncoding, Uint};
 #[cfg(feature = "bigint")]
 use elliptic_curve::ops::Reduce;
+#[cfg(feature = "sec1")]
+use elliptic_curve::{
+    sec1::{CompressedPoint, EncodedPoint, FromEncodedPoint, ModulusSize, ToEncodedPoint},
+    CurveArithmetic, FieldBytesSize,
+};
 use rand_core::{CryptoRng, RngCore};
 #[cfg(feature = "zeroize")]
 use zeroize::DefaultIsZeroes;
@@ -22,6 +28,14 @@ pub struct ValueGroup<G: Group + GroupEncoding + Default>(
     #[cfg_attr(feature = "serde", serde(with = "elliptic_curve_tools::group"))] pub G,
 );
 
+#[cfg(feature = "arbitrary")]
+impl<'a, G: Group + GroupEncoding + Default> arbitrary::Arbitrary<'a> for ValueGroup<G> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let scalar = IdentifierPrimeField::<G::Scalar>::arbitrary(u)?;
+        Ok(Self(G::generator() * scalar.0))
+    }
+}
+
 impl<G: Group + GroupEncoding + Default> Display for ValueGroup<G> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         for &b in self.0.to_bytes().as_ref() {
@@ -31,6 +45,12 @@ impl<G: Group + GroupEncoding + Default> Display for ValueGroup<G> {
     }
 }
 
+impl<G: Group + GroupEncoding + Default> Hash for ValueGroup<G> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bytes().as_ref().hash(state);
+    }
+}
+
 impl<G: Group + GroupEncoding + Default> Deref for ValueGroup<G> {
     type Target = G;
 
@@ -454,3 +474,41 @@ impl<G: Group + GroupEncoding + Default> ValueGroup<G> {
         Self(G::generator())
     }
 }
+
+#[cfg(feature = "sec1")]
+impl<G: Group + GroupEncoding + Default> ValueGroup<G> {
+    /// Encode the group element as a compressed SEC1 point.
+    ///
+    /// Returns [`Error::InvalidShareElement`] for the identity
+    /// which has no compressed encoding.
+    pub fn to_sec1_compressed<C>(&self) -> VsssResult<CompressedPoint<C>>
+    where
+        C: CurveArithmetic<ProjectivePoint = G>,
+        FieldBytesSize<C>: ModulusSize,
+        G: ToEncodedPoint<C>,
+    {
+        let point = self.0.to_encoded_point(true);
+        let mut out = CompressedPoint::<C>::default();
+        if point.as_bytes().len() != out.len() {
+            return Err(Error::InvalidShareElement);
+        }
+        out.copy_from_slice(point.as_bytes());
+        Ok(out)
+    }
+
+    /// Decode a group element from a compressed SEC1 point.
+    pub fn from_sec1_compressed<C>(bytes: &[u8]) -> VsssResult<Self>
+    where
+        C: CurveArithmetic<ProjectivePoint = G>,
+        FieldBytesSize<C>: ModulusSize,
+        G: FromEncodedPoint<C>,
+    {
+        let point = EncodedPoint::<C>::from_bytes(bytes).map_err(|_| Error::InvalidShareElement)?;
+        if !point.is_compressed() {
+            return Err(Error::InvalidShareElement);
+        }
+        Option::from(G::from_encoded_point(&point))
+            .map(Self)
+            .ok_or(Error::InvalidShareElement)
+    }
+}
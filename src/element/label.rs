@@ -0,0 +1,158 @@
+use super::*;
+use crate::*;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::String;
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::{Deref, DerefMut, Mul},
+};
+use elliptic_curve::{Field, PrimeField};
+#[cfg(feature = "std")]
+use std::string::String;
+
+/// A share identifier for a human readable label like an email address.
+///
+/// The label is mapped to a field element with [`hash_to_identifier`]
+/// which is what the identifier uses in all arithmetic.
+/// Serialization is the UTF-8 bytes of the label so deserializing recomputes
+/// the same element. Identifiers from [`ShareElement::zero`] and
+/// [`ShareElement::one`] have an empty label and [`ShareIdentifier::inc`]
+/// only changes the element, so these do not round trip through serialization.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LabelIdentifier<F: PrimeField> {
+    label: String,
+    element: F,
+}
+
+impl<F: PrimeField> Display for LabelIdentifier<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl<F: PrimeField> Deref for LabelIdentifier<F> {
+    type Target = F;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl<F: PrimeField> DerefMut for LabelIdentifier<F> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl<F: PrimeField> AsRef<F> for LabelIdentifier<F> {
+    fn as_ref(&self) -> &F {
+        &self.element
+    }
+}
+
+impl<F: PrimeField> AsMut<F> for LabelIdentifier<F> {
+    fn as_mut(&mut self) -> &mut F {
+        &mut self.element
+    }
+}
+
+impl<F: PrimeField> From<F> for LabelIdentifier<F> {
+    fn from(element: F) -> Self {
+        Self {
+            label: String::new(),
+            element,
+        }
+    }
+}
+
+impl<F: PrimeField> From<&LabelIdentifier<F>> for IdentifierPrimeField<F> {
+    fn from(value: &LabelIdentifier<F>) -> Self {
+        Self(value.element)
+    }
+}
+
+impl<F: PrimeField> Mul<&LabelIdentifier<F>> for IdentifierPrimeField<F> {
+    type Output = IdentifierPrimeField<F>;
+
+    fn mul(self, rhs: &LabelIdentifier<F>) -> Self::Output {
+        Self(self.0 * rhs.element)
+    }
+}
+
+impl<F: PrimeField> ShareElement for LabelIdentifier<F> {
+    type Serialization = Vec<u8>;
+    type Inner = F;
+
+    /// Create an identifier for a random 32 character hex label
+    fn random(mut rng: impl RngCore + CryptoRng) -> Self {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        let mut bytes = [0u8; 16];
+        rng.fill_bytes(&mut bytes);
+        let label = bytes
+            .iter()
+            .flat_map(|b| [HEX[(b >> 4) as usize], HEX[(b & 0x0F) as usize]])
+            .map(char::from)
+            .collect::<String>();
+        Self::from_label(label)
+    }
+
+    fn zero() -> Self {
+        Self::from(<F as Field>::ZERO)
+    }
+
+    fn one() -> Self {
+        Self::from(<F as Field>::ONE)
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.element.is_zero()
+    }
+
+    fn serialize(&self) -> Self::Serialization {
+        self.to_vec()
+    }
+
+    fn deserialize(serialized: &Self::Serialization) -> VsssResult<Self> {
+        Self::from_slice(serialized)
+    }
+
+    fn from_slice(slice: &[u8]) -> VsssResult<Self> {
+        let label = core::str::from_utf8(slice).map_err(|_| Error::InvalidShareElement)?;
+        Ok(Self::new(label))
+    }
+
+    fn to_vec(&self) -> Vec<u8> {
+        self.label.as_bytes().to_vec()
+    }
+}
+
+impl<F: PrimeField> ShareIdentifier for LabelIdentifier<F> {
+    fn inc(&mut self, increment: &Self) {
+        self.element += increment.element;
+    }
+
+    fn invert(&self) -> VsssResult<Self> {
+        let element = Option::<F>::from(self.element.invert()).ok_or(Error::InvalidShareElement)?;
+        Ok(Self {
+            label: self.label.clone(),
+            element,
+        })
+    }
+}
+
+impl<F: PrimeField> LabelIdentifier<F> {
+    /// Create an identifier from a label
+    pub fn new(label: &str) -> Self {
+        Self::from_label(String::from(label))
+    }
+
+    /// The label this identifier was created from
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn from_label(label: String) -> Self {
+        let element = *hash_to_identifier::<IdentifierPrimeField<F>>(label.as_bytes());
+        Self { label, element }
+    }
+}
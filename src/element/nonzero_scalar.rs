@@ -0,0 +1,178 @@
+use crate::*;
+use core::{
+    fmt::{self, Debug, Formatter},
+    ops::{Deref, DerefMut, Mul},
+};
+use elliptic_curve::{CurveArithmetic, NonZeroScalar, PrimeField};
+use rand_core::{CryptoRng, RngCore};
+use subtle::CtOption;
+
+/// A share value for secrets that are nonzero scalars like secret keys.
+///
+/// Create secrets with [`NonZeroScalarValue::new`],
+/// [`NonZeroScalarValue::from_nonzero_scalar`] or [`ShareElement::random`]
+/// which are never zero. Shares and intermediate values during reconstruction
+/// can be zero so the generic conversions still allow it, which is why
+/// [`NonZeroScalarValue::split_secret`] and [`NonZeroScalarValue::combine`]
+/// return [`Error::InvalidSecret`] for a zero secret.
+///
+/// The [`Debug`] output redacts the inner scalar unless the
+/// `debug-secrets` feature is enabled.
+#[derive(Copy, Clone, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct NonZeroScalarValue<F: PrimeField>(F);
+
+impl<F: PrimeField> Debug for NonZeroScalarValue<F> {
+    #[cfg(not(feature = "debug-secrets"))]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "NonZeroScalarValue(REDACTED)")
+    }
+
+    #[cfg(feature = "debug-secrets")]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("NonZeroScalarValue").field(&self.0).finish()
+    }
+}
+
+impl<F: PrimeField> Deref for NonZeroScalarValue<F> {
+    type Target = F;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<F: PrimeField> DerefMut for NonZeroScalarValue<F> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<F: PrimeField> AsRef<F> for NonZeroScalarValue<F> {
+    fn as_ref(&self) -> &F {
+        &self.0
+    }
+}
+
+impl<F: PrimeField> AsMut<F> for NonZeroScalarValue<F> {
+    fn as_mut(&mut self) -> &mut F {
+        &mut self.0
+    }
+}
+
+impl<F: PrimeField> From<F> for NonZeroScalarValue<F> {
+    fn from(value: F) -> Self {
+        Self(value)
+    }
+}
+
+impl<F: PrimeField> From<&IdentifierPrimeField<F>> for NonZeroScalarValue<F> {
+    fn from(value: &IdentifierPrimeField<F>) -> Self {
+        Self(value.0)
+    }
+}
+
+impl<F: PrimeField> Mul<&IdentifierPrimeField<F>> for NonZeroScalarValue<F> {
+    type Output = NonZeroScalarValue<F>;
+
+    fn mul(self, rhs: &IdentifierPrimeField<F>) -> Self::Output {
+        Self(self.0 * rhs.0)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F: PrimeField> zeroize::DefaultIsZeroes for NonZeroScalarValue<F> where
+    F: zeroize::DefaultIsZeroes
+{
+}
+
+impl<F: PrimeField> ShareElement for NonZeroScalarValue<F> {
+    type Serialization = F::Repr;
+    type Inner = F;
+
+    /// Generate a random nonzero scalar
+    fn random(mut rng: impl RngCore + CryptoRng) -> Self {
+        loop {
+            let value = F::random(&mut rng);
+            if !bool::from(value.is_zero()) {
+                return Self(value);
+            }
+        }
+    }
+
+    fn zero() -> Self {
+        Self(F::ZERO)
+    }
+
+    fn one() -> Self {
+        Self(F::ONE)
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.0.is_zero()
+    }
+
+    fn serialize(&self) -> Self::Serialization {
+        self.0.to_repr()
+    }
+
+    fn deserialize(serialized: &Self::Serialization) -> VsssResult<Self> {
+        Option::from(F::from_repr(*serialized).map(Self)).ok_or(Error::InvalidShareElement)
+    }
+
+    fn from_slice(slice: &[u8]) -> VsssResult<Self> {
+        let mut repr = F::Repr::default();
+        if slice.len() != repr.as_ref().len() {
+            return Err(Error::InvalidShareElement);
+        }
+        repr.as_mut().copy_from_slice(slice);
+        Self::deserialize(&repr)
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    fn to_vec(&self) -> Vec<u8> {
+        self.0.to_repr().as_ref().to_vec()
+    }
+}
+
+impl<F: PrimeField> NonZeroScalarValue<F> {
+    /// Create a value from a scalar which is none if it is zero
+    pub fn new(value: F) -> CtOption<Self> {
+        CtOption::new(Self(value), !value.is_zero())
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    /// Split a secret into shares returning [`Error::InvalidSecret`] if it is zero
+    pub fn split_secret<S: Share<Value = Self>>(
+        threshold: usize,
+        limit: usize,
+        secret: &Self,
+        rng: impl RngCore + CryptoRng,
+    ) -> VsssResult<Vec<S>> {
+        if secret.is_zero().into() {
+            return Err(Error::InvalidSecret);
+        }
+        shamir::split_secret::<S>(threshold, limit, secret, rng)
+    }
+
+    /// Combine shares into the secret returning [`Error::InvalidSecret`] if it is zero
+    pub fn combine<S: Share<Value = Self>>(shares: &[S]) -> VsssResult<Self> {
+        shares.combine_nonzero()
+    }
+
+    /// Create a value from a [`NonZeroScalar`]
+    pub fn from_nonzero_scalar<C>(value: NonZeroScalar<C>) -> Self
+    where
+        C: CurveArithmetic<Scalar = F>,
+    {
+        Self(*value)
+    }
+
+    /// Convert to a [`NonZeroScalar`] returning [`Error::InvalidSecret`] if the value is zero
+    pub fn to_nonzero_scalar<C>(&self) -> VsssResult<NonZeroScalar<C>>
+    where
+        C: CurveArithmetic<Scalar = F>,
+    {
+        Option::from(NonZeroScalar::new(self.0)).ok_or(Error::InvalidSecret)
+    }
+}
@@ -0,0 +1,181 @@
+use super::*;
+use crate::*;
+use core::ops::{Deref, DerefMut, Mul};
+use elliptic_curve::{
+    group::{Group, GroupEncoding},
+    Field, PrimeField,
+};
+
+const POINT_IDENTIFIER_DST: &[u8] = b"vsss-rs-point-identifier";
+
+/// A share identifier for a participant identified by a group element
+/// e.g. a public key in a pairing based threshold scheme.
+///
+/// The point is mapped to a scalar by hashing its compressed encoding with
+/// its own domain separation tag, distinct from [`hash_to_identifier`] and
+/// [`LabelIdentifier`], and all interpolation uses that scalar, not the point.
+/// Serialization is the encoding of the point so deserializing recomputes
+/// the same scalar.
+///
+/// Security caveats:
+/// - Anyone can compute the identifier for any point so it says nothing
+///   about who holds the point. Protocols must bind the point to the
+///   participant separately e.g. with a proof of possession.
+/// - Every party must derive identifiers the same way. Interpolating in the
+///   exponent with the points' discrete logs instead of these scalars
+///   gives a different, wrong, result.
+/// - Identifiers from [`ShareElement::zero`] and [`ShareElement::one`] use
+///   the identity point and [`ShareIdentifier::inc`] only changes the scalar,
+///   so these do not round trip through serialization.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct GroupElementIdentifier<G, F = <G as Group>::Scalar>
+where
+    G: Group<Scalar = F> + GroupEncoding,
+    F: PrimeField,
+{
+    point: G,
+    element: F,
+}
+
+impl<G: Group<Scalar = F> + GroupEncoding, F: PrimeField> Default for GroupElementIdentifier<G, F> {
+    fn default() -> Self {
+        Self::from(<F as Field>::ZERO)
+    }
+}
+
+impl<G: Group<Scalar = F> + GroupEncoding, F: PrimeField> Deref for GroupElementIdentifier<G, F> {
+    type Target = F;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl<G: Group<Scalar = F> + GroupEncoding, F: PrimeField> DerefMut
+    for GroupElementIdentifier<G, F>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl<G: Group<Scalar = F> + GroupEncoding, F: PrimeField> AsRef<F>
+    for GroupElementIdentifier<G, F>
+{
+    fn as_ref(&self) -> &F {
+        &self.element
+    }
+}
+
+impl<G: Group<Scalar = F> + GroupEncoding, F: PrimeField> AsMut<F>
+    for GroupElementIdentifier<G, F>
+{
+    fn as_mut(&mut self) -> &mut F {
+        &mut self.element
+    }
+}
+
+impl<G: Group<Scalar = F> + GroupEncoding, F: PrimeField> From<F> for GroupElementIdentifier<G, F> {
+    fn from(element: F) -> Self {
+        Self {
+            point: G::identity(),
+            element,
+        }
+    }
+}
+
+impl<G: Group<Scalar = F> + GroupEncoding, F: PrimeField> From<&GroupElementIdentifier<G, F>>
+    for IdentifierPrimeField<F>
+{
+    fn from(value: &GroupElementIdentifier<G, F>) -> Self {
+        Self(value.element)
+    }
+}
+
+impl<G: Group<Scalar = F> + GroupEncoding, F: PrimeField> Mul<&GroupElementIdentifier<G, F>>
+    for IdentifierPrimeField<F>
+{
+    type Output = IdentifierPrimeField<F>;
+
+    fn mul(self, rhs: &GroupElementIdentifier<G, F>) -> Self::Output {
+        Self(self.0 * rhs.element)
+    }
+}
+
+impl<G: Group<Scalar = F> + GroupEncoding, F: PrimeField> ShareElement
+    for GroupElementIdentifier<G, F>
+{
+    type Serialization = Vec<u8>;
+    type Inner = F;
+
+    /// Create an identifier for a random point
+    fn random(rng: impl RngCore + CryptoRng) -> Self {
+        Self::new(G::random(rng))
+    }
+
+    fn zero() -> Self {
+        Self::from(<F as Field>::ZERO)
+    }
+
+    fn one() -> Self {
+        Self::from(<F as Field>::ONE)
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.element.is_zero()
+    }
+
+    fn serialize(&self) -> Self::Serialization {
+        self.to_vec()
+    }
+
+    fn deserialize(serialized: &Self::Serialization) -> VsssResult<Self> {
+        Self::from_slice(serialized)
+    }
+
+    fn from_slice(slice: &[u8]) -> VsssResult<Self> {
+        let mut repr = G::Repr::default();
+        if repr.as_ref().len() != slice.len() {
+            return Err(Error::InvalidShareElement);
+        }
+        repr.as_mut().copy_from_slice(slice);
+        let point = Option::<G>::from(G::from_bytes(&repr)).ok_or(Error::InvalidShareElement)?;
+        Ok(Self::new(point))
+    }
+
+    fn to_vec(&self) -> Vec<u8> {
+        self.point.to_bytes().as_ref().to_vec()
+    }
+}
+
+impl<G: Group<Scalar = F> + GroupEncoding, F: PrimeField> ShareIdentifier
+    for GroupElementIdentifier<G, F>
+{
+    fn inc(&mut self, increment: &Self) {
+        self.element += increment.element;
+    }
+
+    fn invert(&self) -> VsssResult<Self> {
+        let element = Option::<F>::from(self.element.invert()).ok_or(Error::InvalidShareElement)?;
+        Ok(Self {
+            point: self.point,
+            element,
+        })
+    }
+}
+
+impl<G: Group<Scalar = F> + GroupEncoding, F: PrimeField> GroupElementIdentifier<G, F> {
+    /// Create an identifier from a point
+    pub fn new(point: G) -> Self {
+        let element = *hash_to_identifier_with_dst::<IdentifierPrimeField<F>>(
+            POINT_IDENTIFIER_DST,
+            point.to_bytes().as_ref(),
+        );
+        Self { point, element }
+    }
+
+    /// The point this identifier was created from
+    pub fn point(&self) -> &G {
+        &self.point
+    }
+}
@@ -2,7 +2,7 @@ use super::*;
 use crate::*;
 use core::{
     cmp::Ordering,
-    fmt::{self, Display, Formatter},
+    fmt::{self, Debug, Display, Formatter},
     hash::{Hash, Hasher},
     ops::{Deref, DerefMut, Mul},
 };
@@ -17,13 +17,42 @@ use elliptic_curve::{scalar::IsHigh, Field, PrimeField};
 pub type ValuePrimeField<F> = IdentifierPrimeField<F>;
 
 /// A share identifier represented as a prime field element.
-#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+///
+/// Since this type is also used for share values, the [`Debug`] output
+/// redacts the inner field element unless the `debug-secrets` feature is enabled.
+#[derive(Copy, Clone, Default, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct IdentifierPrimeField<F: PrimeField>(
     #[cfg_attr(feature = "serde", serde(with = "elliptic_curve_tools::prime_field"))] pub F,
 );
 
+#[cfg(feature = "arbitrary")]
+impl<'a, F: PrimeField> arbitrary::Arbitrary<'a> for IdentifierPrimeField<F> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut repr = F::Repr::default();
+        u.fill_buffer(repr.as_mut())?;
+        match Option::<F>::from(F::from_repr(repr)) {
+            Some(f) => Ok(Self(f)),
+            None => Ok(Self(F::from(u64::arbitrary(u)?))),
+        }
+    }
+}
+
+impl<F: PrimeField> Debug for IdentifierPrimeField<F> {
+    #[cfg(not(feature = "debug-secrets"))]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "IdentifierPrimeField(REDACTED)")
+    }
+
+    #[cfg(feature = "debug-secrets")]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("IdentifierPrimeField")
+            .field(&self.0)
+            .finish()
+    }
+}
+
 impl<F: PrimeField> Display for IdentifierPrimeField<F> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         for &b in self.0.to_repr().as_ref() {
@@ -63,6 +63,20 @@ pub type IdentifierIsize = IdentifierPrimitive<isize, ISIZE_BYTES>;
 /// A share value represented as isize
 pub type ValueIsize = IdentifierIsize;
 
+#[cfg(target_pointer_width = "64")]
+const SUPPORTED_IDENTIFIER_WIDTHS: &[usize] = &[1, 2, 4, 8, 16];
+#[cfg(not(target_pointer_width = "64"))]
+const SUPPORTED_IDENTIFIER_WIDTHS: &[usize] = &[1, 2, 4, 8];
+
+/// The byte widths of the primitive integer identifiers available on this target.
+///
+/// The 128-bit identifiers, [`IdentifierU128`] and [`IdentifierI128`], are only
+/// available when `target_pointer_width = "64"`. [`IdentifierUsize`] and
+/// [`IdentifierIsize`] use the native pointer width which is always in this list.
+pub const fn supported_identifier_widths() -> &'static [usize] {
+    SUPPORTED_IDENTIFIER_WIDTHS
+}
+
 /// A share identifier represented as a primitive integer.
 #[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[repr(transparent)]
@@ -274,3 +288,19 @@ mod serde_32 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifier_widths() {
+        let widths = supported_identifier_widths();
+        assert!(widths.contains(&USIZE_BYTES));
+        assert!(widths.contains(&ISIZE_BYTES));
+        #[cfg(target_pointer_width = "64")]
+        assert!(widths.contains(&16));
+        #[cfg(not(target_pointer_width = "64"))]
+        assert!(!widths.contains(&16));
+    }
+}
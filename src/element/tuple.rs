@@ -0,0 +1,224 @@
+use crate::*;
+use core::ops::{Add, AddAssign, Deref, DerefMut, Mul, Sub, SubAssign};
+use rand_core::{CryptoRng, RngCore};
+
+/// A pair of share elements that are added and subtracted component-wise.
+/// This is the inner type of [`TupleShareElement`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ElementPair<A: ShareElement + 'static, B: ShareElement + 'static>(pub A, pub B);
+
+impl<A: ShareElement + 'static, B: ShareElement + 'static> AddAssign<&Self> for ElementPair<A, B> {
+    fn add_assign(&mut self, rhs: &Self) {
+        *self.0.as_mut() += rhs.0.as_ref();
+        *self.1.as_mut() += rhs.1.as_ref();
+    }
+}
+
+impl<A: ShareElement + 'static, B: ShareElement + 'static> AddAssign for ElementPair<A, B> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl<A: ShareElement + 'static, B: ShareElement + 'static> SubAssign<&Self> for ElementPair<A, B> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        *self.0.as_mut() -= rhs.0.as_ref();
+        *self.1.as_mut() -= rhs.1.as_ref();
+    }
+}
+
+impl<A: ShareElement + 'static, B: ShareElement + 'static> SubAssign for ElementPair<A, B> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+impl<A: ShareElement + 'static, B: ShareElement + 'static> Add<&Self> for ElementPair<A, B> {
+    type Output = Self;
+
+    fn add(mut self, rhs: &Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<A: ShareElement + 'static, B: ShareElement + 'static> Add for ElementPair<A, B> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self + &rhs
+    }
+}
+
+impl<A: ShareElement + 'static, B: ShareElement + 'static> Sub<&Self> for ElementPair<A, B> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: &Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<A: ShareElement + 'static, B: ShareElement + 'static> Sub for ElementPair<A, B> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self - &rhs
+    }
+}
+
+/// A share element carrying two share elements so a secret made of
+/// two values like a keypair or commitment opening is covered by one share set.
+///
+/// Shares are created with [`TupleShareElement::split_secret`] and
+/// recovered with [`TupleShareElement::combine`] which deal and combine
+/// each component with its own polynomial. The tuple is deliberately not
+/// usable as a [`Share`] value since the generic splitting functions would
+/// draw a single random coefficient for both components.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct TupleShareElement<A: ShareElement + 'static, B: ShareElement + 'static>(
+    pub ElementPair<A, B>,
+);
+
+impl<A: ShareElement + 'static, B: ShareElement + 'static> Deref for TupleShareElement<A, B> {
+    type Target = ElementPair<A, B>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<A: ShareElement + 'static, B: ShareElement + 'static> DerefMut for TupleShareElement<A, B> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<A: ShareElement + 'static, B: ShareElement + 'static> AsRef<ElementPair<A, B>>
+    for TupleShareElement<A, B>
+{
+    fn as_ref(&self) -> &ElementPair<A, B> {
+        &self.0
+    }
+}
+
+impl<A: ShareElement + 'static, B: ShareElement + 'static> AsMut<ElementPair<A, B>>
+    for TupleShareElement<A, B>
+{
+    fn as_mut(&mut self) -> &mut ElementPair<A, B> {
+        &mut self.0
+    }
+}
+
+impl<A: ShareElement + 'static, B: ShareElement + 'static> From<ElementPair<A, B>>
+    for TupleShareElement<A, B>
+{
+    fn from(value: ElementPair<A, B>) -> Self {
+        Self(value)
+    }
+}
+
+impl<A: ShareElement + 'static, B: ShareElement + 'static> From<(A, B)>
+    for TupleShareElement<A, B>
+{
+    fn from((a, b): (A, B)) -> Self {
+        Self::new(a, b)
+    }
+}
+
+impl<A: ShareElement + 'static, B: ShareElement + 'static> ShareElement
+    for TupleShareElement<A, B>
+{
+    type Serialization = Vec<u8>;
+    type Inner = ElementPair<A, B>;
+
+    fn random(mut rng: impl RngCore + CryptoRng) -> Self {
+        Self::new(A::random(&mut rng), B::random(&mut rng))
+    }
+
+    fn zero() -> Self {
+        Self::new(A::zero(), B::zero())
+    }
+
+    fn one() -> Self {
+        Self::new(A::one(), B::one())
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.0 .0.is_zero() & self.0 .1.is_zero()
+    }
+
+    fn serialize(&self) -> Self::Serialization {
+        let mut out = self.0 .0.to_vec();
+        out.extend_from_slice(self.0 .1.serialize().as_ref());
+        out
+    }
+
+    fn deserialize(serialized: &Self::Serialization) -> VsssResult<Self> {
+        Self::from_slice(serialized)
+    }
+
+    /// The first component must have a fixed length serialization
+    fn from_slice(slice: &[u8]) -> VsssResult<Self> {
+        let a_len = A::zero().serialize().as_ref().len();
+        if slice.len() < a_len {
+            return Err(Error::InvalidShareElement);
+        }
+        let a = A::from_slice(&slice[..a_len])?;
+        let b = B::from_slice(&slice[a_len..])?;
+        Ok(Self::new(a, b))
+    }
+
+    fn to_vec(&self) -> Vec<u8> {
+        self.serialize()
+    }
+}
+
+impl<A: ShareElement + 'static, B: ShareElement + 'static> TupleShareElement<A, B> {
+    /// Create a new tuple from its components
+    pub fn new(a: A, b: B) -> Self {
+        Self(ElementPair(a, b))
+    }
+
+    /// Split a tuple secret into shares dealing each component
+    /// with an independent random polynomial over the same identifiers.
+    pub fn split_secret<I>(
+        threshold: usize,
+        limit: usize,
+        secret: &Self,
+        mut rng: impl RngCore + CryptoRng,
+    ) -> VsssResult<Vec<(I, Self)>>
+    where
+        I: ShareIdentifier,
+        A: for<'a> From<&'a I> + for<'a> Mul<&'a I, Output = A>,
+        B: for<'a> From<&'a I> + for<'a> Mul<&'a I, Output = B>,
+    {
+        let a_shares = shamir::split_secret::<(I, A)>(threshold, limit, &secret.0 .0, &mut rng)?;
+        let b_shares = shamir::split_secret::<(I, B)>(threshold, limit, &secret.0 .1, &mut rng)?;
+        Ok(a_shares
+            .into_iter()
+            .zip(b_shares)
+            .map(|((id, a), (_, b))| (id, Self::new(a, b)))
+            .collect())
+    }
+
+    /// Combine shares created by [`TupleShareElement::split_secret`]
+    /// by combining each component separately.
+    pub fn combine<I>(shares: &[(I, Self)]) -> VsssResult<Self>
+    where
+        I: ShareIdentifier,
+        A: for<'a> From<&'a I> + for<'a> Mul<&'a I, Output = A>,
+        B: for<'a> From<&'a I> + for<'a> Mul<&'a I, Output = B>,
+    {
+        let a_shares = shares
+            .iter()
+            .map(|(id, s)| (id.clone(), s.0 .0.clone()))
+            .collect::<Vec<_>>();
+        let b_shares = shares
+            .iter()
+            .map(|(id, s)| (id.clone(), s.0 .1.clone()))
+            .collect::<Vec<_>>();
+        Ok(Self::new(a_shares.combine()?, b_shares.combine()?))
+    }
+}
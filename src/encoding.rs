@@ -0,0 +1,127 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+//! Text encodings for shares.
+//!
+//! A share is framed as a version byte followed by the big endian `u32`
+//! lengths of the identifier and value bytes then the bytes themselves.
+//! The frame is encoded as unpadded base64url after the `vsss1:` prefix
+//! or as lowercase hex after the `vssh1:` prefix.
+use crate::*;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::String;
+#[cfg(feature = "std")]
+use std::string::String;
+
+/// The prefix for base64url encoded shares
+pub(crate) const BASE64_PREFIX: &str = "vsss1:";
+/// The prefix for hex encoded shares
+pub(crate) const HEX_PREFIX: &str = "vssh1:";
+
+const VERSION: u8 = 1;
+const HEADER_LEN: usize = 9;
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const HEX_ALPHABET: &[u8; 16] = b"0123456789abcdef";
+
+/// Frame the identifier and value bytes with the version and lengths
+pub(crate) fn frame(identifier: &[u8], value: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(HEADER_LEN + identifier.len() + value.len());
+    out.push(VERSION);
+    out.extend_from_slice(&(identifier.len() as u32).to_be_bytes());
+    out.extend_from_slice(&(value.len() as u32).to_be_bytes());
+    out.extend_from_slice(identifier);
+    out.extend_from_slice(value);
+    out
+}
+
+/// Split a frame into the identifier and value bytes
+pub(crate) fn unframe(bytes: &[u8]) -> VsssResult<(&[u8], &[u8])> {
+    if bytes.len() < HEADER_LEN || bytes[0] != VERSION {
+        return Err(Error::InvalidShareConversion);
+    }
+    let mut len = [0u8; 4];
+    len.copy_from_slice(&bytes[1..5]);
+    let identifier_len = u32::from_be_bytes(len) as usize;
+    len.copy_from_slice(&bytes[5..9]);
+    let value_len = u32::from_be_bytes(len) as usize;
+    let body = &bytes[HEADER_LEN..];
+    if identifier_len.checked_add(value_len) != Some(body.len()) {
+        return Err(Error::InvalidShareConversion);
+    }
+    Ok(body.split_at(identifier_len))
+}
+
+/// Encode the frame as text with the given prefix
+pub(crate) fn encode(prefix: &str, bytes: &[u8]) -> String {
+    let mut out = String::from(prefix);
+    if prefix == HEX_PREFIX {
+        for b in bytes {
+            out.push(HEX_ALPHABET[(b >> 4) as usize] as char);
+            out.push(HEX_ALPHABET[(b & 0x0F) as usize] as char);
+        }
+        return out;
+    }
+    for chunk in bytes.chunks(3) {
+        let mut block = [0u8; 3];
+        block[..chunk.len()].copy_from_slice(chunk);
+        let n = u32::from_be_bytes([0, block[0], block[1], block[2]]);
+        for i in 0..=chunk.len() {
+            let index = (n >> (18 - 6 * i)) & 0x3F;
+            out.push(BASE64_ALPHABET[index as usize] as char);
+        }
+    }
+    out
+}
+
+/// Decode text produced by [`encode`] with either prefix
+pub(crate) fn decode(s: &str) -> VsssResult<Vec<u8>> {
+    if let Some(hex) = s.strip_prefix(HEX_PREFIX) {
+        let hex = hex.as_bytes();
+        if hex.len() % 2 != 0 {
+            return Err(Error::InvalidShareConversion);
+        }
+        return hex
+            .chunks(2)
+            .map(|pair| Ok((hex_value(pair[0])? << 4) | hex_value(pair[1])?))
+            .collect();
+    }
+    let b64 = s
+        .strip_prefix(BASE64_PREFIX)
+        .ok_or(Error::InvalidShareConversion)?
+        .as_bytes();
+    if b64.len() % 4 == 1 {
+        return Err(Error::InvalidShareConversion);
+    }
+    let mut out = Vec::with_capacity(b64.len() * 3 / 4);
+    for chunk in b64.chunks(4) {
+        let mut n = 0u32;
+        for (i, c) in chunk.iter().enumerate() {
+            n |= base64_value(*c)? << (18 - 6 * i);
+        }
+        let bytes = n.to_be_bytes();
+        out.extend_from_slice(&bytes[1..chunk.len()]);
+    }
+    Ok(out)
+}
+
+fn hex_value(c: u8) -> VsssResult<u8> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(Error::InvalidShareConversion),
+    }
+}
+
+fn base64_value(c: u8) -> VsssResult<u32> {
+    match c {
+        b'A'..=b'Z' => Ok((c - b'A') as u32),
+        b'a'..=b'z' => Ok((c - b'a' + 26) as u32),
+        b'0'..=b'9' => Ok((c - b'0' + 52) as u32),
+        b'-' => Ok(62),
+        b'_' => Ok(63),
+        _ => Err(Error::InvalidShareConversion),
+    }
+}
@@ -0,0 +1,69 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+//! A canonical envelope for transporting shares as JSON or CBOR.
+use crate::*;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::String;
+#[cfg(feature = "std")]
+use std::string::String;
+
+/// A share in a serialization independent form with a scheme tag.
+///
+/// The identifier and value are stored as the bytes produced by
+/// [`ShareElement::to_vec`] so converting back with [`ShareEnvelope::to_share`]
+/// yields exactly the original share.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ShareEnvelope {
+    /// The scheme the share belongs to, e.g. `"k256-feldman"`
+    pub scheme: String,
+    /// The share identifier bytes
+    pub identifier: Vec<u8>,
+    /// The share value bytes
+    pub value: Vec<u8>,
+}
+
+impl ShareEnvelope {
+    /// Wrap `share` with the `scheme` tag
+    pub fn new<S: Share>(scheme: impl Into<String>, share: &S) -> Self {
+        Self {
+            scheme: scheme.into(),
+            identifier: share.identifier().to_vec(),
+            value: share.value().to_vec(),
+        }
+    }
+
+    /// Convert back to a share
+    pub fn to_share<S: Share>(&self) -> VsssResult<S> {
+        let identifier = S::Identifier::from_slice(&self.identifier)?;
+        let value = S::Value::from_slice(&self.value)?;
+        Ok(S::with_identifier_and_value(identifier, value))
+    }
+
+    #[cfg(feature = "json")]
+    /// Serialize to a JSON string
+    pub fn to_json(&self) -> VsssResult<String> {
+        serde_json::to_string(self).map_err(|_| Error::InvalidShareConversion)
+    }
+
+    #[cfg(feature = "json")]
+    /// Deserialize from a JSON string
+    pub fn from_json(json: &str) -> VsssResult<Self> {
+        serde_json::from_str(json).map_err(|_| Error::InvalidShareConversion)
+    }
+
+    #[cfg(feature = "cbor")]
+    /// Serialize to CBOR
+    pub fn to_cbor(&self) -> VsssResult<Vec<u8>> {
+        let mut out = Vec::new();
+        ciborium::into_writer(self, &mut out).map_err(|_| Error::InvalidShareConversion)?;
+        Ok(out)
+    }
+
+    #[cfg(feature = "cbor")]
+    /// Deserialize from CBOR
+    pub fn from_cbor(cbor: &[u8]) -> VsssResult<Self> {
+        ciborium::from_reader(cbor).map_err(|_| Error::InvalidShareConversion)
+    }
+}
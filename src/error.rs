@@ -35,6 +35,34 @@ pub enum Error {
     InvalidShareElement,
     /// Not enough share identifiers available when creating shares
     NotEnoughShareIdentifiers,
+    /// A share has a different length than the other shares
+    InvalidShareLength {
+        /// The index of the first mismatched share
+        index: usize,
+        /// The expected share length
+        expected: usize,
+        /// The actual share length
+        actual: usize,
+    },
+    /// The shares in a set do not belong to a single field
+    InvalidShareSet,
+    /// The number of commitments does not match the threshold
+    InvalidCommitmentCount {
+        /// The expected number of commitments
+        expected: usize,
+        /// The actual number of commitments
+        actual: usize,
+    },
+    /// Independent dealings of a secret do not reconstruct the same value
+    InconsistentDealings,
+    /// The share at this index in the supplied shares is invalid
+    ShareAtIndexInvalid(usize),
+    /// The identifier of the share at `index` is outside the valid
+    /// range of identifiers e.g. zero
+    IdentifierOutOfRange {
+        /// The index of the share in the supplied shares
+        index: usize,
+    },
 }
 
 impl Display for Error {
@@ -65,9 +93,58 @@ impl Display for Error {
             Error::NotImplemented => write!(f, "Not implemented"),
             Error::InvalidShareElement => write!(f, "Invalid share element"),
             Error::NotEnoughShareIdentifiers => write!(f, "Not enough share identifiers available"),
+            Error::InvalidShareLength {
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Share at index {} has length {} but expected {}",
+                index, actual, expected
+            ),
+            Error::InvalidShareSet => write!(f, "Shares do not belong to the same field"),
+            Error::InvalidCommitmentCount { expected, actual } => write!(
+                f,
+                "Expected {} commitments but received {}",
+                expected, actual
+            ),
+            Error::InconsistentDealings => {
+                write!(f, "Dealings do not reconstruct the same secret")
+            }
+            Error::ShareAtIndexInvalid(index) => write!(f, "Share at index {} is invalid", index),
+            Error::IdentifierOutOfRange { index } => {
+                write!(f, "Share at index {} has an invalid identifier", index)
+            }
         }
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
 /// Results returned by this crate
 pub type VsssResult<T> = Result<T, Error>;
+
+/// Errors from combining shares read from a fallible source
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CombineOrSourceError<E> {
+    /// The source failed to produce a share
+    Source(E),
+    /// The shares could not be combined
+    Combine(Error),
+}
+
+impl<E> From<Error> for CombineOrSourceError<E> {
+    fn from(value: Error) -> Self {
+        Self::Combine(value)
+    }
+}
+
+impl<E: Display> Display for CombineOrSourceError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Source(e) => write!(f, "Failed to read share: {}", e),
+            Self::Combine(e) => write!(f, "{}", e),
+        }
+    }
+}
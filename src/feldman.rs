@@ -14,6 +14,8 @@ use generic_array::{
     typenum::{Add1, Sub1, B1},
     ArrayLength, GenericArray,
 };
+#[cfg(feature = "hybrid-array")]
+use hybrid_array::{Array, ArraySize};
 use rand_core::{CryptoRng, RngCore};
 
 /// A secret sharing scheme that uses feldman commitments as verifiers
@@ -57,6 +59,9 @@ where
         rng: impl RngCore + CryptoRng,
         participant_generators: &[ParticipantIdGeneratorType<S::Identifier>],
     ) -> VsssResult<(Self::ShareSet, Self::VerifierSet)> {
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!("split_secret", scheme = "feldman", threshold, limit).entered();
         check_params(threshold, limit)?;
         let g = generator.unwrap_or_else(V::one);
         if g.is_zero().into() {
@@ -128,6 +133,51 @@ where
     type VerifierSet = GenericArray<V, Add1<THRESHOLD>>;
 }
 
+/// A default feldman implementation using [`hybrid_array::Array`]
+#[cfg(feature = "hybrid-array")]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(docsrs, doc(cfg(feature = "hybrid-array")))]
+pub struct HybridArrayFeldmanVsss<S, V, THRESHOLD, SHARES>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+    SHARES: ArraySize,
+    THRESHOLD: Add<B1> + ArraySize,
+    Add1<THRESHOLD>: ArraySize + Sub<B1, Output = THRESHOLD>,
+    Sub1<Add1<THRESHOLD>>: ArraySize,
+{
+    /// Marker for the share type
+    pub marker: PhantomData<(S, V, Add1<THRESHOLD>, SHARES)>,
+}
+
+#[cfg(feature = "hybrid-array")]
+impl<S, V, THRESHOLD, SHARES> Shamir<S> for HybridArrayFeldmanVsss<S, V, THRESHOLD, SHARES>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+    SHARES: ArraySize,
+    THRESHOLD: Add<B1> + ArraySize,
+    Add1<THRESHOLD>: ArraySize + Sub<B1, Output = THRESHOLD>,
+    Sub1<Add1<THRESHOLD>>: ArraySize,
+{
+    type InnerPolynomial = Array<S, THRESHOLD>;
+    type ShareSet = Array<S, SHARES>;
+}
+
+#[cfg(feature = "hybrid-array")]
+impl<S, V, THRESHOLD, SHARES> Feldman<S, V> for HybridArrayFeldmanVsss<S, V, THRESHOLD, SHARES>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+    SHARES: ArraySize,
+    THRESHOLD: Add<B1> + ArraySize,
+    Add1<THRESHOLD>: ArraySize + Sub<B1, Output = THRESHOLD>,
+    Sub1<Add1<THRESHOLD>>: ArraySize,
+{
+    type VerifierSet = Array<V, Add1<THRESHOLD>>;
+}
+
 #[cfg(any(feature = "alloc", feature = "std"))]
 /// Create shares from a secret.
 /// `generator` is the point to use for computing feldman verifiers.
@@ -146,6 +196,171 @@ where
     StdVsss::split_secret_with_verifier(threshold, limit, secret, generator, rng)
 }
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Create shares from a secret and return them with their verifiers as a [`Dealing`].
+pub fn deal<S, V>(
+    threshold: usize,
+    limit: usize,
+    secret: &S::Value,
+    generator: Option<V>,
+    rng: impl RngCore + CryptoRng,
+) -> VsssResult<Dealing<S, V>>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    let (shares, verifier_set) = split_secret(threshold, limit, secret, generator, rng)?;
+    Ok(Dealing {
+        shares,
+        verifier_set,
+    })
+}
+
+/// The shares and feldman verifiers created by [`deal`]
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[derive(Debug, Clone)]
+pub struct Dealing<S, V>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    /// The secret shares
+    pub(crate) shares: Vec<S>,
+    /// The feldman verifier set
+    pub(crate) verifier_set: Vec<V>,
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<S, V> Dealing<S, V>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    /// The secret shares
+    pub fn shares(&self) -> &[S] {
+        &self.shares
+    }
+
+    /// The commitments to the polynomial coefficients
+    pub fn verifiers(&self) -> &[V] {
+        FeldmanVerifierSet::<S, V>::verifiers(&self.verifier_set)
+    }
+
+    /// The generator used for the commitments
+    pub fn generator(&self) -> V {
+        FeldmanVerifierSet::<S, V>::generator(&self.verifier_set)
+    }
+
+    /// The verifier set with the generator and commitments
+    pub fn verifier_set(&self) -> &Vec<V> {
+        &self.verifier_set
+    }
+
+    /// Verify every share against the verifiers.
+    /// Returns [`Error::ShareAtIndexInvalid`] with the index of the
+    /// first share that does not verify.
+    pub fn verify_all(&self) -> VsssResult<()> {
+        FeldmanVerifierSet::<S, V>::verify_shares(&self.verifier_set, &self.shares)
+    }
+
+    /// Return the shares and verifier set
+    pub fn into_parts(self) -> (Vec<S>, Vec<V>) {
+        (self.shares, self.verifier_set)
+    }
+}
+
+/// Verifies feldman shares that may arrive before their verifier set.
+///
+/// Shares added before [`PendingVerifier::set_commitments`] are buffered
+/// and verified once the verifier set arrives. Shares added afterwards
+/// are verified immediately.
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[derive(Debug, Clone)]
+pub struct PendingVerifier<S, V>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    /// The shares waiting for the verifier set
+    pub(crate) pending: Vec<S>,
+    /// The feldman verifier set once it arrives
+    pub(crate) verifier_set: Option<Vec<V>>,
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<S, V> Default for PendingVerifier<S, V>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    fn default() -> Self {
+        Self {
+            pending: Vec::new(),
+            verifier_set: None,
+        }
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<S, V> PendingVerifier<S, V>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    /// Create a verifier with no shares or verifier set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Verify `share` if the verifier set has arrived
+    /// otherwise buffer it and return `None`.
+    pub fn add_share(&mut self, share: S) -> Option<VsssResult<()>> {
+        match &self.verifier_set {
+            Some(verifier_set) => Some(FeldmanVerifierSet::<S, V>::verify_share(
+                verifier_set,
+                &share,
+            )),
+            None => {
+                self.pending.push(share);
+                None
+            }
+        }
+    }
+
+    /// Set the verifier set with the generator and commitments and verify
+    /// every buffered share, returning the results in the order the shares
+    /// were added.
+    ///
+    /// Returns [`Error::InvalidSizeRequest`] if the verifier set was already set
+    /// and [`Error::InvalidGenerator`] if `verifier_set` doesn't hold the
+    /// generator and at least one commitment.
+    pub fn set_commitments(&mut self, verifier_set: Vec<V>) -> VsssResult<Vec<VsssResult<()>>> {
+        if self.verifier_set.is_some() {
+            return Err(Error::InvalidSizeRequest);
+        }
+        if verifier_set.len() < 2 {
+            return Err(Error::InvalidGenerator("verifier set is empty"));
+        }
+        let results = self
+            .pending
+            .drain(..)
+            .map(|share| FeldmanVerifierSet::<S, V>::verify_share(&verifier_set, &share))
+            .collect();
+        self.verifier_set = Some(verifier_set);
+        Ok(results)
+    }
+
+    /// The shares waiting for the verifier set
+    pub fn pending(&self) -> &[S] {
+        &self.pending
+    }
+
+    /// The verifier set if it has arrived
+    pub fn verifier_set(&self) -> Option<&Vec<V>> {
+        self.verifier_set.as_ref()
+    }
+}
+
 #[cfg(any(feature = "alloc", feature = "std"))]
 /// Create shares from a secret and a participant number generator.
 pub fn split_secret_with_participant_generator<S, V>(
@@ -169,3 +384,169 @@ where
         participant_generators,
     )
 }
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Create one share per participant public key.
+/// Each identifier is derived from the public key using [`hash_to_identifier`]
+/// so shares can be matched to their owners without a separate index.
+/// Public keys that map to the same identifier are rejected.
+pub fn split_secret_by_pubkeys<S, V>(
+    threshold: usize,
+    secret: &S::Value,
+    pubkeys: &[&[u8]],
+    rng: impl RngCore + CryptoRng,
+) -> VsssResult<(Vec<S>, Vec<V>)>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    let ids = pubkeys
+        .iter()
+        .map(|pk| hash_to_identifier::<S::Identifier>(pk))
+        .collect::<Vec<_>>();
+    split_secret_for_ids(threshold, secret, None, &ids, rng)
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Create one share for each identifier in `ids` in the same order
+/// e.g. to deal to a subset of a larger identifier pool.
+/// `generator` is the point to use for computing feldman verifiers.
+/// If None, the default generator is used.
+/// Zero and duplicate identifiers are rejected.
+pub fn split_secret_for_ids<S, V>(
+    threshold: usize,
+    secret: &S::Value,
+    generator: Option<V>,
+    ids: &[S::Identifier],
+    rng: impl RngCore + CryptoRng,
+) -> VsssResult<(Vec<S>, Vec<V>)>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    shamir::check_identifiers(ids)?;
+    StdVsss::split_secret_with_participant_generator_and_verifiers(
+        threshold,
+        ids.len(),
+        secret,
+        generator,
+        rng,
+        &[ParticipantIdGeneratorType::list(ids)],
+    )
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Create `copies` independent dealings of the same secret.
+/// Each dealing uses fresh polynomial randomness so the dealings
+/// can be checked against each other with [`cross_validate_dealings`].
+pub fn split_secret_redundant<S, V>(
+    threshold: usize,
+    limit: usize,
+    secret: &S::Value,
+    copies: usize,
+    mut rng: impl RngCore + CryptoRng,
+) -> VsssResult<Vec<(Vec<S>, Vec<V>)>>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    if copies == 0 {
+        return Err(Error::InvalidSizeRequest);
+    }
+    (0..copies)
+        .map(|_| split_secret::<S, V>(threshold, limit, secret, None, &mut rng))
+        .collect()
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Check that independent dealings of a secret are each valid
+/// and all reconstruct to the same secret which is returned.
+///
+/// Returns [`Error::InvalidShare`] if a share fails verification against
+/// its dealing's verifiers and [`Error::InconsistentDealings`] if the
+/// dealings reconstruct to different secrets.
+pub fn cross_validate_dealings<S, V>(dealings: &[(Vec<S>, Vec<V>)]) -> VsssResult<S::Value>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    let mut expected: Option<S::Value> = None;
+    for (shares, verifiers) in dealings {
+        for share in shares {
+            FeldmanVerifierSet::<S, V>::verify_share(verifiers, share)?;
+        }
+        let secret = shares.combine()?;
+        match &expected {
+            None => expected = Some(secret),
+            Some(e) if *e != secret => return Err(Error::InconsistentDealings),
+            Some(_) => {}
+        }
+    }
+    expected.ok_or(Error::InvalidSizeRequest)
+}
+
+/// Compute the public key `generator * secret` from a quorum of secret shares
+/// by interpolating `generator * y_i` in the exponent.
+///
+/// The secret itself is never formed, only each share's public point.
+/// The caller still holds every secret share of the quorum though, so this
+/// only avoids the secret appearing in memory. When the public points are
+/// available, e.g. each holder published `generator * y_i`, use
+/// [`public_key_from_public_shares`] so no secret shares are needed at all.
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub fn public_key_from_shares<S, V>(shares: &[S], generator: V) -> VsssResult<V>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    let points = shares
+        .iter()
+        .map(|s| (s.identifier().clone(), generator * s.value().clone()))
+        .collect::<Vec<_>>();
+    public_key_from_public_shares::<S, V>(&points, generator)
+}
+
+/// Compute the public key `generator * secret` from a quorum of public shares
+/// where each point is the share's value multiplied by `generator`.
+///
+/// See [`FeldmanVerifierSet::reconstruct_from_public_shares`].
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub fn public_key_from_public_shares<S, V>(
+    points: &[(S::Identifier, V)],
+    generator: V,
+) -> VsssResult<V>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    let verifiers =
+        <Vec<V> as FeldmanVerifierSet<S, V>>::reconstruct_from_public_shares(generator, points)?;
+    FeldmanVerifierSet::<S, V>::public_key(&verifiers)
+}
+
+/// Recompute the public point `generator * y_j` of the participant with
+/// identifier `id` from a quorum of public shares `generator * y_i`.
+///
+/// This is the group analog of [`ReadableShareSet::combine_to_point`]
+/// so a participant's expected public share can be checked without
+/// knowing any secret share.
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub fn recover_public_share_at<S, V>(
+    points: &[(S::Identifier, V)],
+    generator: V,
+    id: &S::Identifier,
+) -> VsssResult<V>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    if id.is_zero().into() {
+        return Err(Error::SharingInvalidIdentifier);
+    }
+    let verifiers =
+        <Vec<V> as FeldmanVerifierSet<S, V>>::reconstruct_from_public_shares(generator, points)?;
+    Ok(evaluate_commitments::<S, V>(
+        FeldmanVerifierSet::<S, V>::verifiers(&verifiers),
+        id,
+    ))
+}
@@ -1,11 +1,24 @@
 //! Represents Galois Field of 2^8 elements. This uses constant time operations
 //! for all operations as related to shamir secret sharing. Too many implementations
 //! use lookup tables which help for speed but leak secret information.
-//! No lookup tables are used in this implementation because Cryptographic operations should
+//! No lookup tables are used in this implementation by default because Cryptographic operations should
 //!
 //! 1. Ensure runtime is independent of secret data
 //! 2. Ensure code access patterns are independent of secret data
 //! 3. Ensure data access patterns are independent of secret data
+//!
+//! Constant time guarantees per operation
+//!
+//! - Addition, subtraction, multiplication, division and negation are constant time.
+//! - [`Field::invert`] and [`Gf256::pow_ct`] (and [`Gf256::pow`]) are constant time.
+//! - [`Gf256::pow_vartime`] is variable time in the exponent.
+//! - [`Field::sqrt_ratio`] and [`Field::sqrt`] are NOT constant time.
+//!
+//! The `gf256-tables` feature switches multiplication with the [`Mul`] operators,
+//! and thus [`Gf256::split_array`] and [`Gf256::combine_array`], to log/exp lookup
+//! tables. This is several times faster but multiplication is then NOT constant time
+//! and leaks the operands through timing and cache access patterns. Only enable it
+//! for non-secret data like erasure coding public data.
 
 use crate::util::CtIsNotZero;
 use crate::*;
@@ -25,6 +38,8 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 #[cfg(any(feature = "alloc", feature = "std"))]
 use crate::ParticipantIdGeneratorType;
 use rand_core::CryptoRng;
+#[cfg(feature = "std")]
+use std::io::{self, Read, Write};
 #[cfg(feature = "zeroize")]
 use zeroize::DefaultIsZeroes;
 
@@ -37,6 +52,17 @@ type GfShare = DefaultShare<IdentifierGf256, IdentifierGf256>;
 #[repr(transparent)]
 pub struct Gf256(pub u8);
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Gf256 {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(u8::arbitrary(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u8::size_hint(depth)
+    }
+}
+
 #[cfg(feature = "zeroize")]
 impl DefaultIsZeroes for Gf256 {}
 
@@ -168,7 +194,7 @@ impl Mul for Gf256 {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self {
-        Self(gf256_mul(self.0, rhs.0))
+        Self(gf256_mul_op(self.0, rhs.0))
     }
 }
 
@@ -421,15 +447,12 @@ impl Field for Gf256 {
         self + self
     }
 
+    /// Constant time inversion computing `self^254`.
     fn invert(&self) -> CtOption<Self> {
-        let mut z = self.0;
-        for _ in 0..6 {
-            z = gf256_mul(z, z);
-            z = gf256_mul(z, self.0);
-        }
-        CtOption::new(Self(gf256_mul(z, z)), self.0.ct_is_not_zero())
+        CtOption::new(self.pow_ct(254), self.0.ct_is_not_zero())
     }
 
+    /// NOT constant time. The loop runs a data dependent number of iterations.
     fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
         let p = 0x1bu8; // Prime field characteristic for GF(256)
         let pm1d2 = (p - 1) >> 1;
@@ -555,11 +578,39 @@ impl PrimeField for Gf256 {
 }
 
 impl Gf256 {
+    #[cfg(feature = "std")]
+    /// The number of secret bytes [`Gf256::split_array_streaming`] reads and splits at a time
+    pub const STREAMING_CHUNK_SIZE: usize = 4096;
+
     /// Raise the element to the power of `exp`.
+    /// This is constant time, see [`Self::pow_ct`].
     pub fn pow(&self, exp: u8) -> Self {
+        self.pow_ct(exp)
+    }
+
+    /// Raise the element to the power of `exp` in constant time
+    /// with respect to both the element and the exponent.
+    pub fn pow_ct(&self, exp: u8) -> Self {
         Self(gf256_pow(self.0, exp))
     }
 
+    /// Raise the element to the power of `exp`.
+    ///
+    /// This is variable time in `exp` so only use it with public exponents.
+    pub fn pow_vartime(&self, exp: u8) -> Self {
+        let mut result = 1u8;
+        let mut base = self.0;
+        let mut exp = exp;
+        while exp != 0 {
+            if exp & 1 == 1 {
+                result = gf256_mul(result, base);
+            }
+            base = gf256_mul(base, base);
+            exp >>= 1;
+        }
+        Self(result)
+    }
+
     #[cfg(any(feature = "alloc", feature = "std"))]
     /// Split a byte array into shares.
     pub fn split_array<B: AsRef<[u8]>>(
@@ -577,6 +628,53 @@ impl Gf256 {
         )
     }
 
+    #[cfg(feature = "std")]
+    /// Split the bytes read from `reader` into shares written to `writers`,
+    /// one writer per participant.
+    ///
+    /// The secret is read and split [`Gf256::STREAMING_CHUNK_SIZE`] bytes
+    /// at a time so memory use does not grow with the size of the secret.
+    /// Each writer receives the same bytes as the corresponding share from
+    /// [`Gf256::split_array`], i.e. the identifier byte followed by one byte
+    /// per secret byte, so the output can be combined with [`Gf256::combine_array`].
+    ///
+    /// Errors from splitting are returned as [`io::ErrorKind::InvalidInput`]
+    /// wrapping the [`Error`].
+    pub fn split_array_streaming<R: Read, W: Write>(
+        threshold: usize,
+        limit: usize,
+        mut reader: R,
+        writers: &mut [W],
+        mut rng: impl RngCore + CryptoRng,
+    ) -> io::Result<()> {
+        let invalid_input = |e: Error| io::Error::new(io::ErrorKind::InvalidInput, e);
+        if writers.len() != limit {
+            return Err(invalid_input(Error::InvalidSizeRequest));
+        }
+        let mut chunk = vec![0u8; Self::STREAMING_CHUNK_SIZE];
+        let mut first = true;
+        loop {
+            let len = read_chunk(&mut reader, &mut chunk)?;
+            if len == 0 {
+                if first {
+                    return Err(invalid_input(Error::InvalidSecret));
+                }
+                break;
+            }
+            let shares = Self::split_array(threshold, limit, &chunk[..len], &mut rng)
+                .map_err(invalid_input)?;
+            let start = if first { 0 } else { 1 };
+            for (writer, share) in writers.iter_mut().zip(&shares) {
+                writer.write_all(&share[start..])?;
+            }
+            first = false;
+            if len < chunk.len() {
+                break;
+            }
+        }
+        writers.iter_mut().try_for_each(|w| w.flush())
+    }
+
     #[cfg(any(feature = "alloc", feature = "std"))]
     /// Split a byte array into shares using the participant number generator.
     pub fn split_array_with_participant_generators<B: AsRef<[u8]>>(
@@ -629,52 +727,178 @@ impl Gf256 {
 
         Self::are_shares_valid(shares)?;
 
-        let mut secret = Vec::with_capacity(shares[0].len() - 1);
-        let mut inner_shares = Vec::<GfShare>::with_capacity(shares[0].len() - 1);
+        let mut secret = vec![0u8; shares[0].len() - 1];
+        Self::combine_bytes_into(shares, &mut secret)?;
+        Ok(secret)
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    /// Combine shares borrowed from other buffers into a byte array.
+    ///
+    /// Same as [`Gf256::combine_array`] but the shares can be slices
+    /// into a larger received buffer instead of owned vectors.
+    pub fn combine_array_slices(shares: &[&[u8]]) -> VsssResult<Vec<u8>> {
+        Self::are_shares_valid(shares)?;
+
+        let mut secret = vec![0u8; shares[0].len() - 1];
+        Self::combine_bytes_into(shares, &mut secret)?;
+        Ok(secret)
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    /// Combine shares into a byte array after checking that every
+    /// share has the same length and that the identifier bytes are
+    /// nonzero and unique.
+    ///
+    /// Returns [`Error::InvalidShareLength`] with the index of the first share
+    /// whose length differs from the first share, [`Error::SharingInvalidIdentifier`]
+    /// for a zero identifier, and [`Error::SharingDuplicateIdentifier`]
+    /// for a repeated identifier.
+    pub fn combine_array_checked<B: AsRef<[Vec<u8>]>>(shares: B) -> VsssResult<Vec<u8>> {
+        let shares = shares.as_ref();
 
+        Self::are_shares_valid(shares)?;
+        let mut seen = [false; 256];
         for share in shares {
-            inner_shares.push(DefaultShare {
-                identifier: IdentifierGf256(Gf256(share[0])),
-                value: IdentifierGf256(Gf256(0u8)),
-            });
-        }
-        for i in 1..shares[0].len() {
-            for (inner_share, share) in inner_shares.iter_mut().zip(shares.iter()) {
-                inner_share.value = IdentifierGf256(Gf256(share[i]));
+            let id = share[0] as usize;
+            if id == 0 {
+                return Err(Error::SharingInvalidIdentifier);
             }
-            secret.push(inner_shares.combine()?.0 .0);
+            if seen[id] {
+                return Err(Error::SharingDuplicateIdentifier);
+            }
+            seen[id] = true;
         }
+
+        let mut secret = vec![0u8; shares[0].len() - 1];
+        Self::combine_bytes_into(shares, &mut secret)?;
         Ok(secret)
     }
 
     #[cfg(any(feature = "alloc", feature = "std"))]
-    fn are_shares_valid(shares: &[Vec<u8>]) -> VsssResult<()> {
+    /// Combine shares into a fixed size byte array.
+    /// The secret is zero padded to `N` bytes so the output length
+    /// doesn't reveal the secret length.
+    pub fn combine_array_fixed<const N: usize, B: AsRef<[Vec<u8>]>>(
+        shares: B,
+    ) -> VsssResult<[u8; N]> {
+        let shares = shares.as_ref();
+
+        Self::are_shares_valid(shares)?;
+        if shares[0].len() - 1 > N {
+            return Err(Error::InvalidSizeRequest);
+        }
+
+        let mut secret = [0u8; N];
+        Self::combine_bytes_into(shares, &mut secret[..shares[0].len() - 1])?;
+        Ok(secret)
+    }
+
+    /// Combine shares into a caller provided buffer without allocating.
+    ///
+    /// The reconstructed secret is written to the start of `out` and
+    /// its length is returned. The remaining bytes of `out` are left
+    /// unchanged. Returns [`Error::InvalidSizeRequest`] if the secret
+    /// doesn't fit in `N` bytes.
+    pub fn combine_array_into<const N: usize, T: AsRef<[u8]>>(
+        shares: &[T],
+        out: &mut [u8; N],
+    ) -> VsssResult<usize> {
+        Self::are_shares_valid(shares)?;
+        let len = shares[0].as_ref().len() - 1;
+        if len > N {
+            return Err(Error::InvalidSizeRequest);
+        }
+        Self::combine_bytes_into(shares, &mut out[..len])?;
+        Ok(len)
+    }
+
+    fn combine_bytes_into<T: AsRef<[u8]>>(shares: &[T], secret: &mut [u8]) -> VsssResult<()> {
+        // There are only 255 valid identifiers so more shares must repeat one
+        let mut buffer = [(IdentifierGf256::default(), IdentifierGf256::default()); 255];
+        if shares.len() > buffer.len() {
+            return Err(Error::SharingDuplicateIdentifier);
+        }
+        let inner_shares = &mut buffer[..shares.len()];
+
+        for (inner_share, share) in inner_shares.iter_mut().zip(shares.iter()) {
+            inner_share.0 = IdentifierGf256(Gf256(share.as_ref()[0]));
+        }
+        for (i, s) in secret.iter_mut().enumerate() {
+            for (inner_share, share) in inner_shares.iter_mut().zip(shares.iter()) {
+                inner_share.1 = IdentifierGf256(Gf256(share.as_ref()[i + 1]));
+            }
+            *s = (&*inner_shares).combine()?.0 .0;
+        }
+        Ok(())
+    }
+
+    fn are_shares_valid<T: AsRef<[u8]>>(shares: &[T]) -> VsssResult<()> {
         if shares.len() < 2 {
             return Err(Error::SharingMinThreshold);
         }
-        if shares[0].len() < 2 {
+        if shares[0].as_ref().len() < 2 {
             return Err(Error::InvalidShare);
         }
-        if shares[1..].iter().any(|s| s.len() != shares[0].len()) {
-            return Err(Error::InvalidShare);
+        let expected = shares[0].as_ref().len();
+        if let Some((index, share)) = shares
+            .iter()
+            .enumerate()
+            .skip(1)
+            .find(|(_, s)| s.as_ref().len() != expected)
+        {
+            return Err(Error::InvalidShareLength {
+                index,
+                expected,
+                actual: share.as_ref().len(),
+            });
         }
         Ok(())
     }
 }
 
+#[cfg(feature = "std")]
+/// Fill `buffer` from `reader` stopping early only at the end of the input
+fn read_chunk<R: Read>(reader: &mut R, buffer: &mut [u8]) -> io::Result<usize> {
+    let mut len = 0;
+    while len < buffer.len() {
+        match reader.read(&mut buffer[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(len)
+}
+
 fn gf256_pow(base: u8, exp: u8) -> u8 {
     let mut result = 1;
-    for i in 0..8 {
-        result *= result;
-        let mut tmp = result;
-        tmp *= base;
+    for i in (0..8).rev() {
+        result = gf256_mul(result, result);
+        let tmp = gf256_mul(result, base);
         let allow = ((exp >> i) & 1).ct_eq(&1);
         result.conditional_assign(&tmp, allow);
     }
-    result.conditional_assign(&1, exp.ct_eq(&0));
     result
 }
 
+/// The multiplication used by the [`Mul`] operators.
+///
+/// With the `gf256-tables` feature this uses log/exp lookup tables
+/// which is faster but NOT constant time.
+#[inline]
+fn gf256_mul_op(a: u8, b: u8) -> u8 {
+    #[cfg(feature = "gf256-tables")]
+    {
+        gf256_tables::gf256_mul(a, b)
+    }
+    #[cfg(not(feature = "gf256-tables"))]
+    {
+        gf256_mul(a, b)
+    }
+}
+
 fn gf256_mul(a: u8, b: u8) -> u8 {
     let mut a = a as i8;
     let mut b = b as i8;
@@ -699,6 +923,17 @@ fn gf256_mul(a: u8, b: u8) -> u8 {
 /// the start number and the incremented number instead of adding.
 pub struct IdentifierGf256(pub Gf256);
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for IdentifierGf256 {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(Gf256::arbitrary(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Gf256::size_hint(depth)
+    }
+}
+
 impl Display for IdentifierGf256 {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -815,16 +1050,81 @@ impl IdentifierGf256 {
     pub const ONE: Self = Self(Gf256(1));
 }
 
+#[cfg(test)]
+mod no_alloc_tests {
+    use super::*;
+
+    #[test]
+    fn combine_array_into() {
+        // 2 of 3 shares of `vsss` with f(x) = secret + a * x + b * x^2
+        let shares = [
+            [0x01, 0x4f, 0x68, 0x86, 0x2c],
+            [0x02, 0xf8, 0xf6, 0xfd, 0xe0],
+            [0x03, 0xc1, 0xed, 0x08, 0xbf],
+        ];
+        let mut out = [0xAAu8; 8];
+        let len = Gf256::combine_array_into(&shares, &mut out).unwrap();
+        assert_eq!(len, 4);
+        assert_eq!(&out[..len], b"vsss");
+        assert_eq!(&out[len..], &[0xAAu8; 4]);
+
+        let mut exact = [0u8; 4];
+        assert_eq!(Gf256::combine_array_into(&shares, &mut exact), Ok(4));
+        assert_eq!(&exact, b"vsss");
+
+        let mut small = [0u8; 3];
+        assert_eq!(
+            Gf256::combine_array_into(&shares, &mut small),
+            Err(Error::InvalidSizeRequest)
+        );
+        assert_eq!(
+            Gf256::combine_array_into(&shares[..1], &mut out),
+            Err(Error::SharingMinThreshold)
+        );
+        assert_eq!(
+            Gf256::combine_array_into(&[shares[0], shares[0], shares[1]], &mut out),
+            Err(Error::SharingDuplicateIdentifier)
+        );
+    }
+}
+
 #[cfg(test)]
 #[cfg(any(feature = "alloc", feature = "std"))]
 mod tests {
-    use super::gf256_cmp;
+    use super::gf256_tables;
     use super::*;
     use crate::shamir;
     use rand::{Rng, SeedableRng};
     use rand_chacha::ChaCha8Rng;
     use std::prelude::v1::Vec;
 
+    #[test]
+    fn test_vectors() {
+        let (secret, shares) = test_vectors::generate::<Gf256>(b"vsss-rs", 3, 5).unwrap();
+        assert_eq!(secret, [0x0c]);
+        assert_eq!(
+            shares,
+            [
+                [0x01, 0x77],
+                [0x02, 0xa1],
+                [0x03, 0xda],
+                [0x04, 0x3a],
+                [0x05, 0x41]
+            ]
+        );
+        assert_eq!(Gf256::combine_array(&shares[2..]).unwrap(), secret);
+    }
+
+    #[test]
+    fn mul_tables() {
+        for a in 0..=255u8 {
+            for b in 0..=255u8 {
+                assert_eq!(gf256_mul(a, b), gf256_tables::gf256_mul(a, b));
+                assert_eq!(gf256_mul_op(a, b), gf256_mul(a, b));
+            }
+        }
+    }
+
     #[test]
     fn compatibility() {
         let mut rng = ChaCha8Rng::from_seed([57u8; 32]);
@@ -834,7 +1134,7 @@ mod tests {
             let y = Gf256(a);
             let z = Gf256(b);
 
-            assert_eq!((y * z).0, gf256_cmp::gf256_mul(a, b));
+            assert_eq!((y * z).0, gf256_tables::gf256_mul(a, b));
         }
         rng = ChaCha8Rng::from_entropy();
         for _ in 0..1000 {
@@ -843,7 +1143,7 @@ mod tests {
             let y = Gf256(a);
             let z = Gf256(b);
 
-            assert_eq!((y * z).0, gf256_cmp::gf256_mul(a, b));
+            assert_eq!((y * z).0, gf256_tables::gf256_mul(a, b));
         }
 
         let mut rng = ChaCha8Rng::from_seed([57u8; 32]);
@@ -854,7 +1154,24 @@ mod tests {
             }
             let y = Gf256(a);
 
-            assert_eq!(y.invert().unwrap().0, gf256_cmp::gf256_div(1, a));
+            assert_eq!(y.invert().unwrap().0, gf256_tables::gf256_div(1, a));
+        }
+    }
+
+    #[test]
+    fn pow() {
+        for b in 0..=255u8 {
+            let base = Gf256(b);
+            let expected = (0..254).fold(Gf256::ONE, |acc, _| acc * base);
+            assert_eq!(base.pow_ct(254), expected);
+            assert_eq!(base.pow_ct(254), base.pow(254));
+            assert_eq!(base.pow_vartime(254), base.pow(254));
+            assert_eq!(base.pow_ct(0), Gf256::ONE);
+            assert_eq!(base.pow_ct(1), base);
+            if b != 0 {
+                assert_eq!(base * base.pow_ct(254), Gf256::ONE);
+                assert_eq!(base.pow_ct(255), Gf256::ONE);
+            }
         }
     }
 
@@ -898,6 +1215,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn combine_best_quorum() {
+        let mut rng = ChaCha8Rng::from_seed([59u8; 32]);
+        let secret = IdentifierGf256(Gf256(42));
+        let shares = shamir::split_secret::<GfShare>(3, 5, &secret, &mut rng).unwrap();
+
+        // The first three shares repeat an identifier so their difference is zero
+        let mut candidates = vec![shares[0], shares[0], shares[1]];
+        candidates.push(GfShare::with_identifier_and_value(
+            IdentifierGf256::zero(),
+            shares[2].value,
+        ));
+        candidates.extend_from_slice(&shares[3..]);
+        assert_eq!(
+            (&candidates[..3]).combine().unwrap_err(),
+            Error::SharingDuplicateIdentifier
+        );
+        assert_eq!(candidates.combine_best_quorum(3).unwrap(), secret);
+        assert_eq!(
+            (&candidates[..4]).combine_best_quorum(3).unwrap_err(),
+            Error::NotEnoughShareIdentifiers
+        );
+        assert_eq!(
+            candidates.combine_best_quorum(1).unwrap_err(),
+            Error::SharingMinThreshold
+        );
+    }
+
     #[test]
     fn split_array() {
         let mut rng = ChaCha8Rng::from_seed([57u8; 32]);
@@ -945,11 +1290,193 @@ mod tests {
             assert!(Gf256::combine_array(shares).is_err());
         }
     }
+
+    #[test]
+    fn combine_array_fixed() {
+        let mut rng = ChaCha8Rng::from_seed([58u8; 32]);
+        let secret = b"Hello World!";
+        let shares = Gf256::split_array(3, 5, secret, &mut rng).unwrap();
+
+        let res = Gf256::combine_array_fixed::<32, _>(&shares[..3]);
+        let mut expected = [0u8; 32];
+        expected[..secret.len()].copy_from_slice(secret);
+        assert_eq!(res.unwrap(), expected);
+
+        let res = Gf256::combine_array_fixed::<12, _>(&shares[2..]);
+        assert_eq!(&res.unwrap(), secret);
+
+        let res = Gf256::combine_array_fixed::<8, _>(&shares);
+        assert_eq!(res, Err(Error::InvalidSizeRequest));
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn combine_array_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut rng = ChaCha8Rng::from_seed([59u8; 32]);
+        let mut data = [0u8; 1024];
+        for _ in 0..64 {
+            rng.fill_bytes(&mut data);
+            let mut u = Unstructured::new(&data);
+            let shares = Vec::<Vec<u8>>::arbitrary(&mut u).unwrap();
+            let _ = Gf256::combine_array(&shares);
+            let _ = Gf256::combine_array_fixed::<32, _>(&shares);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn split_array_streaming() {
+        use std::io::Cursor;
+
+        let mut rng = ChaCha8Rng::from_seed([55u8; 32]);
+        let mut secret = vec![0u8; 1024 * 1024];
+        rng.fill_bytes(&mut secret);
+        let mut writers = vec![Vec::new(); 5];
+        Gf256::split_array_streaming(3, 5, Cursor::new(&secret), &mut writers, &mut rng).unwrap();
+        for (i, share) in writers.iter().enumerate() {
+            assert_eq!(share.len(), secret.len() + 1);
+            assert_eq!(share[0], i as u8 + 1);
+        }
+        assert_eq!(Gf256::combine_array(&writers[1..4]).unwrap(), secret);
+
+        let mut writers = vec![Vec::new(); 4];
+        let err = Gf256::split_array_streaming(3, 5, Cursor::new(&secret), &mut writers, &mut rng)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.get_ref().and_then(|e| e.downcast_ref::<Error>()),
+            Some(&Error::InvalidSizeRequest)
+        );
+        let mut writers = vec![Vec::new(); 5];
+        let err = Gf256::split_array_streaming(3, 5, Cursor::new(&[]), &mut writers, &mut rng)
+            .unwrap_err();
+        assert_eq!(
+            err.get_ref().and_then(|e| e.downcast_ref::<Error>()),
+            Some(&Error::InvalidSecret)
+        );
+    }
+
+    #[test]
+    fn combine_array_into() {
+        let mut rng = ChaCha8Rng::from_seed([58u8; 32]);
+        let secret = b"Hello World!";
+        let shares = Gf256::split_array(3, 5, secret, &mut rng).unwrap();
+
+        // Fixed size shares like a device without an allocator would hold
+        let mut fixed = [[0u8; 13]; 3];
+        for (f, s) in fixed.iter_mut().zip(shares[1..4].iter()) {
+            f.copy_from_slice(s);
+        }
+        let mut out = [0xAAu8; 16];
+        let len = Gf256::combine_array_into(&fixed, &mut out).unwrap();
+        assert_eq!(len, 12);
+        assert_eq!(&out[..len], secret);
+        assert_eq!(&out[len..], &[0xAAu8; 4]);
+
+        let mut small = [0u8; 8];
+        assert_eq!(
+            Gf256::combine_array_into(&fixed, &mut small),
+            Err(Error::InvalidSizeRequest)
+        );
+        assert_eq!(
+            Gf256::combine_array_into(&fixed[..1], &mut out),
+            Err(Error::SharingMinThreshold)
+        );
+    }
+
+    #[test]
+    fn combine_array_slices() {
+        let mut rng = ChaCha8Rng::from_seed([57u8; 32]);
+        let secret = b"Hello World!";
+        let shares = Gf256::split_array(3, 5, secret, &mut rng).unwrap();
+
+        // Shares received back to back in one buffer
+        let buffer = shares.concat();
+        let share_len = shares[0].len();
+        let borrowed = buffer.chunks(share_len).collect::<Vec<_>>();
+        assert_eq!(
+            Gf256::combine_array_slices(&borrowed[1..4]).unwrap(),
+            Gf256::combine_array(&shares[1..4]).unwrap()
+        );
+        assert_eq!(
+            &Gf256::combine_array_slices(&borrowed[2..]).unwrap(),
+            secret
+        );
+
+        assert_eq!(
+            Gf256::combine_array_slices(&borrowed[..1]),
+            Err(Error::SharingMinThreshold)
+        );
+        let short = [borrowed[0], &borrowed[1][..share_len - 1]];
+        assert_eq!(
+            Gf256::combine_array_slices(&short),
+            Err(Error::InvalidShareLength {
+                index: 1,
+                expected: share_len,
+                actual: share_len - 1,
+            })
+        );
+    }
+
+    #[test]
+    fn combine_array_checked() {
+        let mut rng = ChaCha8Rng::from_seed([56u8; 32]);
+        let secret = b"Hello World!";
+        let mut shares = Gf256::split_array(3, 5, secret, &mut rng).unwrap();
+        assert_eq!(
+            &Gf256::combine_array_checked(&shares[1..4]).unwrap(),
+            secret
+        );
+
+        shares[4][0] = shares[1][0];
+        assert_eq!(
+            Gf256::combine_array_checked(&shares[1..]),
+            Err(Error::SharingDuplicateIdentifier)
+        );
+        shares[4][0] = 0;
+        assert_eq!(
+            Gf256::combine_array_checked(&shares[1..]),
+            Err(Error::SharingInvalidIdentifier)
+        );
+        assert_eq!(
+            Gf256::combine_array(&shares[1..]),
+            Err(Error::SharingInvalidIdentifier)
+        );
+
+        shares[2].push(0);
+        assert_eq!(
+            Gf256::combine_array_checked(&shares[..4]),
+            Err(Error::InvalidShareLength {
+                index: 2,
+                expected: secret.len() + 1,
+                actual: secret.len() + 2,
+            })
+        );
+    }
+
+    #[test]
+    fn combine_array_reports_mismatched_share() {
+        let mut rng = ChaCha8Rng::from_seed([57u8; 32]);
+        let secret = b"Hello World!";
+        let mut shares = Gf256::split_array(3, 5, secret, &mut rng).unwrap();
+        shares[3].truncate(7);
+
+        let res = Gf256::combine_array(&shares);
+        assert_eq!(
+            res,
+            Err(Error::InvalidShareLength {
+                index: 3,
+                expected: secret.len() + 1,
+                actual: 7,
+            })
+        );
+    }
 }
 
-#[cfg(test)]
-#[cfg(any(feature = "alloc", feature = "std"))]
-mod gf256_cmp {
+#[cfg(any(test, feature = "gf256-tables"))]
+mod gf256_tables {
     // Ref https://github.com/veracruz-project/veracruz/blob/main/sdk/data-generators/shamir-secret-sharing/src/main.rs
 
     #[rustfmt::skip]
@@ -1067,6 +1594,7 @@ mod gf256_cmp {
         }
     }
 
+    #[cfg(test)]
     /// Divide in GF(256)/
     pub fn gf256_div(a: u8, b: u8) -> u8 {
         // multiply `a` against inverse `b`
@@ -145,12 +145,12 @@
 //! let res = shamir::split_secret::<Ed25519Share>(2, 3, &secret, &mut osrng);
 //! assert!(res.is_ok());
 //! let shares = res.unwrap();
-//! let res = shares.combine();
+//! let res = curve25519::combine_to_scalar_bytes(&shares);
 //! assert!(res.is_ok());
-//! let scalar = res.unwrap();
-//! assert_eq!(scalar.0.0, sc);
-//! let sk2 = StaticSecret::from(scalar.0.0.to_bytes());
-//! let ske2 = SigningKey::from_bytes(&scalar.0.0.to_bytes());
+//! let bytes = res.unwrap();
+//! assert_eq!(bytes, sc.to_bytes());
+//! let sk2 = StaticSecret::from(bytes);
+//! let ske2 = SigningKey::from_bytes(&bytes);
 //! assert_eq!(sk2.to_bytes(), sk1.to_bytes());
 //! assert_eq!(ske1.to_bytes(), ske2.to_bytes());
 //! }
@@ -189,7 +189,15 @@ pub mod macros;
 #[cfg(test)]
 pub(crate) mod tests;
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod additive;
+#[cfg(all(feature = "bigint", any(feature = "alloc", feature = "std")))]
+pub mod asmuth_bloom;
 mod element;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod encoding;
+#[cfg(any(feature = "json", feature = "cbor"))]
+mod envelope;
 mod error;
 pub mod feldman;
 mod fixed_array;
@@ -199,19 +207,28 @@ mod gf256;
 mod numbering;
 pub mod pedersen;
 mod polynomial;
+#[cfg(feature = "prime-field-u256")]
+#[allow(missing_docs)]
+mod prime_field_u256;
 #[cfg(feature = "primitive")]
 mod primitive;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod refresh;
 #[cfg(feature = "bigint")]
 mod saturating;
 mod set;
 pub mod shamir;
 mod share;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod test_vectors;
 mod util;
 
 use shamir::check_params;
 use subtle::*;
 
 pub use element::*;
+#[cfg(any(feature = "json", feature = "cbor"))]
+pub use envelope::*;
 pub use error::*;
 pub use feldman::Feldman;
 pub use fixed_array::*;
@@ -219,6 +236,8 @@ pub use gf256::*;
 pub use numbering::*;
 pub use pedersen::{Pedersen, PedersenResult};
 pub use polynomial::*;
+#[cfg(feature = "prime-field-u256")]
+pub use prime_field_u256::*;
 #[cfg(feature = "primitive")]
 pub use primitive::*;
 #[cfg(feature = "bigint")]
@@ -34,6 +34,17 @@ pub enum ParticipantIdGeneratorType<'a, I: ShareIdentifier> {
         /// The total number of identifiers to generate
         count: usize,
     },
+    /// Resume a [`ParticipantIdGeneratorType::Random`] generator with the same
+    /// `seed` and `count` after the first `skip` identifiers.
+    /// This generates the remaining `count - skip` identifiers then stops.
+    Checkpoint {
+        /// The seed to use for the random number generator
+        seed: [u8; 32],
+        /// The total number of identifiers of the original generator
+        count: usize,
+        /// The number of identifiers already generated
+        skip: usize,
+    },
     /// Use the provided list of identifiers
     List {
         /// The list of identifiers to use. Once all have been used the generator will stop
@@ -62,6 +73,13 @@ impl<I: ShareIdentifier + Display> Display for ParticipantIdGeneratorType<'_, I>
                 }
                 write!(f, ", count: {} }}", count)
             }
+            Self::Checkpoint { seed, count, skip } => {
+                write!(f, "Checkpoint {{ seed: ")?;
+                for &b in seed {
+                    write!(f, "{:02x}", b)?;
+                }
+                write!(f, ", count: {}, skip: {} }}", count, skip)
+            }
             Self::List { list } => {
                 write!(f, "List {{ list: ")?;
                 for id in list.iter() {
@@ -114,6 +132,17 @@ impl<'a, I: ShareIdentifier> ParticipantIdGeneratorType<'a, I> {
         }
     }
 
+    /// Create a new random participant number generator that resumes
+    /// after the first `skip` identifiers of [`Self::random`] with the same
+    /// `seed` and `count`
+    pub fn checkpoint(seed: [u8; 32], count: NonZeroUsize, skip: usize) -> Self {
+        Self::Checkpoint {
+            seed,
+            count: count.get(),
+            skip,
+        }
+    }
+
     /// Create a new list participant number generator
     pub fn list(list: &'a [I]) -> Self {
         Self::List { list }
@@ -147,12 +176,22 @@ impl<'a, I: ShareIdentifier> ParticipantIdGeneratorType<'a, I> {
                     ));
                 }
                 Ok(ParticipantIdGeneratorState::Random(
-                    RandomParticipantNumberGenerator {
-                        dst: *seed,
-                        index: 0,
-                        count: *count,
-                        _markers: PhantomData,
-                    },
+                    RandomParticipantNumberGenerator::new(*seed, *count, 0),
+                ))
+            }
+            Self::Checkpoint { seed, count, skip } => {
+                if *count == 0 {
+                    return Err(Error::InvalidGenerator(
+                        "The count must be greater than zero",
+                    ));
+                }
+                if *skip >= *count {
+                    return Err(Error::InvalidGenerator(
+                        "The skip must be less than the count",
+                    ));
+                }
+                Ok(ParticipantIdGeneratorState::Random(
+                    RandomParticipantNumberGenerator::new(*seed, *count, *skip),
                 ))
             }
             Self::List { list } => Ok(ParticipantIdGeneratorState::List(
@@ -279,12 +318,18 @@ impl<I: ShareIdentifier> Iterator for SequentialParticipantNumberGenerator<I> {
 }
 
 /// A generator that creates random participant identifiers
+///
+/// Identifiers equal to one already generated by this generator are
+/// skipped so at most `count` distinct identifiers are returned.
 #[derive(Debug)]
 pub(crate) struct RandomParticipantNumberGenerator<I: ShareIdentifier> {
     /// Domain separation tag
     dst: [u8; 32],
     index: usize,
     count: usize,
+    /// The identifiers generated so far
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    seen: crate::Vec<I>,
     _markers: PhantomData<I>,
 }
 
@@ -292,21 +337,64 @@ impl<I: ShareIdentifier> Iterator for RandomParticipantNumberGenerator<I> {
     type Item = I;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.count {
-            return None;
+        while self.index < self.count {
+            self.index += 1;
+            let id = self.identifier_at(self.index);
+            if self.is_duplicate(&id) {
+                continue;
+            }
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            self.seen.push(id.clone());
+            return Some(id);
         }
-        self.index += 1;
-        Some(I::random(self.get_rng(self.index)))
+        None
     }
 }
 
 impl<I: ShareIdentifier> RandomParticipantNumberGenerator<I> {
+    /// Create a generator that resumes after the first `skip` indices
+    fn new(dst: [u8; 32], count: usize, skip: usize) -> Self {
+        #[allow(unused_mut)]
+        let mut generator = Self {
+            dst,
+            index: skip,
+            count,
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            seen: crate::Vec::new(),
+            _markers: PhantomData,
+        };
+        #[cfg(any(feature = "alloc", feature = "std"))]
+        for index in 1..=skip {
+            let id = generator.identifier_at(index);
+            if !generator.seen.contains(&id) {
+                generator.seen.push(id);
+            }
+        }
+        generator
+    }
+
+    fn identifier_at(&self, index: usize) -> I {
+        I::random(self.get_rng(index))
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    fn is_duplicate(&self, id: &I) -> bool {
+        self.seen.contains(id)
+    }
+
+    /// Without an allocator the earlier identifiers are regenerated
+    /// since each one only depends on its index
+    #[cfg(not(any(feature = "alloc", feature = "std")))]
+    fn is_duplicate(&self, id: &I) -> bool {
+        (1..self.index).any(|index| self.identifier_at(index) == *id)
+    }
+
     fn get_rng(&self, index: usize) -> XofRng {
         let mut hasher = Shake256::default();
         hasher.update(&self.dst);
         hasher.update(&index.to_be_bytes());
         hasher.update(&self.count.to_be_bytes());
-        XofRng(hasher.finalize_xof())
+        XofRng::new(hasher)
     }
 }
 
@@ -330,9 +418,34 @@ impl<'a, I: ShareIdentifier> Iterator for ListParticipantNumberGenerator<'a, I>
     }
 }
 
+/// Hash arbitrary bytes like a participant's public key to a share identifier.
+///
+/// The same input always maps to the same identifier.
+pub fn hash_to_identifier<I: ShareIdentifier>(data: &[u8]) -> I {
+    hash_to_identifier_with_dst(b"vsss-rs-participant-identifier", data)
+}
+
+/// Hash `data` to a share identifier under the domain separation tag `dst`
+/// so identifiers derived for different purposes never collide.
+pub(crate) fn hash_to_identifier_with_dst<I: ShareIdentifier>(dst: &[u8], data: &[u8]) -> I {
+    let mut hasher = Shake256::default();
+    hasher.update(dst);
+    hasher.update(&(data.len() as u64).to_be_bytes());
+    hasher.update(data);
+    I::random(XofRng::new(hasher))
+}
+
+/// A deterministic RNG reading from the output of a Shake256 hash
 #[derive(Clone)]
 #[repr(transparent)]
-struct XofRng(<Shake256 as ExtendableOutput>::Reader);
+pub(crate) struct XofRng(<Shake256 as ExtendableOutput>::Reader);
+
+impl XofRng {
+    /// Read from the output of `hasher` after everything it has absorbed
+    pub(crate) fn new(hasher: Shake256) -> Self {
+        Self(hasher.finalize_xof())
+    }
+}
 
 impl RngCore for XofRng {
     fn next_u32(&mut self) -> u32 {
@@ -397,12 +510,7 @@ mod tests {
         let mut rng = rand_chacha::ChaCha8Rng::from_seed([1u8; 32]);
         let mut dst = [0u8; 32];
         rng.fill_bytes(&mut dst);
-        let gen = RandomParticipantNumberGenerator::<IdentifierPrimeField<Scalar>> {
-            dst,
-            index: 0,
-            count: 5,
-            _markers: PhantomData,
-        };
+        let gen = RandomParticipantNumberGenerator::<IdentifierPrimeField<Scalar>>::new(dst, 5, 0);
         let list: Vec<_> = gen.collect();
         assert_eq!(list.len(), 5);
         let mut repr = FieldBytes::default();
@@ -424,6 +532,67 @@ mod tests {
         }
     }
 
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn test_random_participant_number_generator_unique() {
+        let seed = [3u8; 32];
+        let count = 64;
+        let gen = RandomParticipantNumberGenerator::<IdentifierGf256>::new(seed, count, 0);
+        // Only 256 possible values so 64 draws collide
+        let raw = (1..=count)
+            .map(|i| gen.identifier_at(i))
+            .collect::<Vec<_>>();
+        let distinct = raw
+            .iter()
+            .enumerate()
+            .filter(|(i, id)| !raw[..*i].contains(id))
+            .map(|(_, id)| *id)
+            .collect::<Vec<_>>();
+        assert!(distinct.len() < count);
+
+        let list: Vec<_> = gen.collect();
+        assert_eq!(list, distinct);
+
+        // Resuming keeps skipping identifiers from before the checkpoint
+        let resumed: Vec<_> =
+            RandomParticipantNumberGenerator::<IdentifierGf256>::new(seed, count, 32).collect();
+        let before = distinct.iter().filter(|id| raw[..32].contains(id)).count();
+        assert_eq!(resumed, distinct[before..]);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn test_checkpoint_participant_number_generator() {
+        let seed = [7u8; 32];
+        let count = NonZeroUsize::new(10).unwrap();
+        let random =
+            [ParticipantIdGeneratorType::<IdentifierPrimeField<Scalar>>::random(seed, count)];
+        let all: Vec<_> = ParticipantIdGeneratorCollection::from(&random)
+            .iter()
+            .collect();
+        assert_eq!(all.len(), 10);
+
+        let checkpoint = [
+            ParticipantIdGeneratorType::<IdentifierPrimeField<Scalar>>::checkpoint(seed, count, 5),
+        ];
+        let resumed: Vec<_> = ParticipantIdGeneratorCollection::from(&checkpoint)
+            .iter()
+            .collect();
+        assert_eq!(resumed, all[5..]);
+
+        let checkpoint = [
+            ParticipantIdGeneratorType::<IdentifierPrimeField<Scalar>>::checkpoint(seed, count, 0),
+        ];
+        let resumed: Vec<_> = ParticipantIdGeneratorCollection::from(&checkpoint)
+            .iter()
+            .collect();
+        assert_eq!(resumed, all);
+
+        let checkpoint =
+            ParticipantIdGeneratorType::<IdentifierPrimeField<Scalar>>::checkpoint(seed, count, 10);
+        assert!(checkpoint.try_into_generator().is_err());
+    }
+
     #[cfg(any(feature = "alloc", feature = "std"))]
     #[test]
     fn test_list_participant_number_generator() {
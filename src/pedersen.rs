@@ -15,6 +15,8 @@ use generic_array::{
     typenum::{Add1, Sub1, B1, U2},
     ArrayLength, GenericArray,
 };
+#[cfg(feature = "hybrid-array")]
+use hybrid_array::{Array, ArraySize};
 use rand_core::{CryptoRng, RngCore};
 
 /// Options for Pedersen secret sharing
@@ -30,6 +32,11 @@ pub struct PedersenOptions<'a, S: Share, V: ShareVerifier<S>> {
     pub blinder_generator: Option<V>,
     /// The participant id generators to use for shares
     pub participant_generators: &'a [ParticipantIdGeneratorType<'a, S::Identifier>],
+    /// Feldman commitments `g * a_i` to the secret polynomial created by another
+    /// protocol step, e.g. the [`FeldmanVerifierSet::verifiers`] from Gennaro's DKG.
+    /// If set, splitting fails unless they match the commitments
+    /// to the secret polynomial so both steps are known to share it.
+    pub reuse_feldman_commitments: Option<&'a [V]>,
 }
 
 /// A secret sharing scheme that uses pedersen commitments as verifiers
@@ -67,6 +74,9 @@ where
         options: &PedersenOptions<S, V>,
         mut rng: impl RngCore + CryptoRng,
     ) -> VsssResult<Self::PedersenResult> {
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!("split_secret", scheme = "pedersen", threshold, limit).entered();
         check_params(threshold, limit)?;
         let g = options.secret_generator.unwrap_or_else(V::one);
         let h = options
@@ -92,24 +102,29 @@ where
         secret_polynomial.fill(&options.secret, &mut rng, threshold)?;
         blinder_polynomial.fill(&blinder, &mut rng, threshold)?;
 
-        let mut feldman_verifier_set =
-            Self::FeldmanVerifierSet::empty_feldman_set_with_capacity(threshold, g);
-        let mut pedersen_verifier_set =
-            Self::PedersenVerifierSet::empty_pedersen_set_with_capacity(threshold, g, h);
         // Generate the verifiable commitments to the polynomial for the shares
         // Each share is multiple of the polynomial and the specified generator point.
         // {g^p0, g^p1, g^p2, ..., g^pn}
-        let secret_coefficients = secret_polynomial.coefficients();
-        let blinder_coefficients = blinder_polynomial.coefficients();
-        let feldman_verifiers = feldman_verifier_set.verifiers_mut();
-        let pedersen_verifiers = pedersen_verifier_set.blind_verifiers_mut();
-
-        feldman_verifiers[0] = g * secret_coefficients[0].value();
-        pedersen_verifiers[0] = feldman_verifiers[0] + h * blinder_coefficients[0].value();
-
-        for i in 1..threshold {
-            feldman_verifiers[i] = g * secret_coefficients[i].identifier();
-            pedersen_verifiers[i] = feldman_verifiers[i] + h * blinder_coefficients[i].identifier();
+        let (feldman_verifier_set, pedersen_verifier_set) = secret_polynomial
+            .commit_dual::<V, Self::FeldmanVerifierSet, Self::PedersenVerifierSet>(
+            g,
+            h,
+            &blinder_polynomial,
+            threshold,
+        )?;
+        let feldman_verifiers = feldman_verifier_set.verifiers();
+        if let Some(commitments) = options.reuse_feldman_commitments {
+            if commitments.len() != threshold {
+                return Err(Error::InvalidCommitmentCount {
+                    expected: threshold,
+                    actual: commitments.len(),
+                });
+            }
+            if commitments != &feldman_verifiers[..threshold] {
+                return Err(Error::InvalidGenerator(
+                    "Feldman commitments do not match the secret polynomial",
+                ));
+            }
         }
         let secret_shares = create_shares_with_participant_generator(
             &secret_polynomial,
@@ -174,6 +189,11 @@ where
 type Add2<A> = <A as Add<U2>>::Output;
 type Sub2<A> = <A as Sub<U2>>::Output;
 /// The result to use when the sizes are known or computed at compile time
+///
+/// `THRESHOLD` is the number of polynomial coefficients and `SHARES` the
+/// number of shares. The feldman verifier set holds the generator followed
+/// by `THRESHOLD` commitments so its length is `THRESHOLD + 1`, and the
+/// pedersen verifier set holds both generators so its length is `THRESHOLD + 2`.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
@@ -223,6 +243,9 @@ where
         feldman_verifier_set: Self::FeldmanVerifierSet,
         pedersen_verifier_set: Self::PedersenVerifierSet,
     ) -> Self {
+        debug_assert_eq!(feldman_verifier_set.len(), THRESHOLD::USIZE + 1);
+        debug_assert_eq!(pedersen_verifier_set.len(), THRESHOLD::USIZE + 2);
+        debug_assert_eq!(secret_shares.len(), blinder_shares.len());
         Self {
             blinder,
             secret_shares,
@@ -284,6 +307,122 @@ where
     type PedersenResult = Self;
 }
 
+/// The result to use when the sizes are known or computed at compile time
+/// backed by [`hybrid_array::Array`]
+#[cfg(feature = "hybrid-array")]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(docsrs, doc(cfg(feature = "hybrid-array")))]
+pub struct HybridArrayPedersenResult<S, V, THRESHOLD, SHARES>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+    SHARES: ArraySize,
+    THRESHOLD: Add<B1> + Add<U2> + ArraySize,
+    Add1<THRESHOLD>: ArraySize + Sub<B1, Output = THRESHOLD>,
+    Add2<THRESHOLD>: ArraySize + Sub<U2, Output = THRESHOLD>,
+    Sub1<Add1<THRESHOLD>>: ArraySize,
+    Sub2<Add2<THRESHOLD>>: ArraySize,
+{
+    /// The blinder used to create pedersen commitments
+    pub(crate) blinder: S::Value,
+    /// The secret shares
+    pub(crate) secret_shares: Array<S, SHARES>,
+    /// The blinder shares
+    pub(crate) blinder_shares: Array<S, SHARES>,
+    /// The feldman verifiers
+    pub(crate) feldman_verifier_set: Array<V, Add1<THRESHOLD>>,
+    /// The pedersen verifiers
+    pub(crate) pedersen_verifier_set: Array<V, Add2<THRESHOLD>>,
+}
+
+#[cfg(feature = "hybrid-array")]
+impl<S, V, THRESHOLD, SHARES> PedersenResult<S, V>
+    for HybridArrayPedersenResult<S, V, THRESHOLD, SHARES>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+    SHARES: ArraySize,
+    THRESHOLD: Add<B1> + Add<U2> + ArraySize,
+    Add1<THRESHOLD>: ArraySize + Sub<B1, Output = THRESHOLD>,
+    Add2<THRESHOLD>: ArraySize + Sub<U2, Output = THRESHOLD>,
+    Sub1<Add1<THRESHOLD>>: ArraySize,
+    Sub2<Add2<THRESHOLD>>: ArraySize,
+{
+    type ShareSet = Array<S, SHARES>;
+    type FeldmanVerifierSet = Array<V, Add1<THRESHOLD>>;
+    type PedersenVerifierSet = Array<V, Add2<THRESHOLD>>;
+
+    fn new(
+        blinder: S::Value,
+        secret_shares: Self::ShareSet,
+        blinder_shares: Self::ShareSet,
+        feldman_verifier_set: Self::FeldmanVerifierSet,
+        pedersen_verifier_set: Self::PedersenVerifierSet,
+    ) -> Self {
+        Self {
+            blinder,
+            secret_shares,
+            blinder_shares,
+            feldman_verifier_set,
+            pedersen_verifier_set,
+        }
+    }
+
+    fn blinder(&self) -> &S::Value {
+        &self.blinder
+    }
+
+    fn secret_shares(&self) -> &Self::ShareSet {
+        &self.secret_shares
+    }
+
+    fn blinder_shares(&self) -> &Self::ShareSet {
+        &self.blinder_shares
+    }
+
+    fn feldman_verifier_set(&self) -> &Self::FeldmanVerifierSet {
+        &self.feldman_verifier_set
+    }
+
+    fn pedersen_verifier_set(&self) -> &Self::PedersenVerifierSet {
+        &self.pedersen_verifier_set
+    }
+}
+
+#[cfg(feature = "hybrid-array")]
+impl<S, V, THRESHOLD, SHARES> Shamir<S> for HybridArrayPedersenResult<S, V, THRESHOLD, SHARES>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+    SHARES: ArraySize,
+    THRESHOLD: Add<B1> + Add<U2> + ArraySize,
+    Add1<THRESHOLD>: ArraySize + Sub<B1, Output = THRESHOLD>,
+    Add2<THRESHOLD>: ArraySize + Sub<U2, Output = THRESHOLD>,
+    Sub1<Add1<THRESHOLD>>: ArraySize,
+    Sub2<Add2<THRESHOLD>>: ArraySize,
+{
+    type InnerPolynomial = Array<S, THRESHOLD>;
+    type ShareSet = Array<S, SHARES>;
+}
+
+#[cfg(feature = "hybrid-array")]
+impl<S, V, THRESHOLD, SHARES> Pedersen<S, V> for HybridArrayPedersenResult<S, V, THRESHOLD, SHARES>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+    SHARES: ArraySize,
+    THRESHOLD: Add<B1> + Add<U2> + ArraySize,
+    Add1<THRESHOLD>: ArraySize + Sub<B1, Output = THRESHOLD>,
+    Add2<THRESHOLD>: ArraySize + Sub<U2, Output = THRESHOLD>,
+    Sub1<Add1<THRESHOLD>>: ArraySize,
+    Sub2<Add2<THRESHOLD>>: ArraySize,
+{
+    type FeldmanVerifierSet = Array<V, Add1<THRESHOLD>>;
+    type PedersenVerifierSet = Array<V, Add2<THRESHOLD>>;
+    type PedersenResult = Self;
+}
+
 /// The result to use when an allocator is available
 #[cfg(any(feature = "alloc", feature = "std"))]
 #[derive(Debug, Clone)]
@@ -402,7 +541,168 @@ where
             secret_generator: share_generator,
             blinder_generator: blind_factor_generator,
             participant_generators: &[ParticipantIdGeneratorType::default()],
+            reuse_feldman_commitments: None,
+        },
+        rng,
+    )
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Create one share for each identifier in `ids` in the same order
+/// e.g. to deal to a subset of a larger identifier pool.
+///
+/// `blinding`, `share_generator`, and `blind_factor_generator`
+/// behave the same as in [`split_secret`].
+/// Zero and duplicate identifiers are rejected.
+pub fn split_secret_for_ids<S, V>(
+    threshold: usize,
+    secret: &S::Value,
+    blinding: Option<S::Value>,
+    share_generator: Option<V>,
+    blind_factor_generator: Option<V>,
+    ids: &[S::Identifier],
+    rng: impl RngCore + CryptoRng,
+) -> VsssResult<StdPedersenResult<S, V>>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    shamir::check_identifiers(ids)?;
+    StdVsss::split_secret_with_blind_verifiers(
+        threshold,
+        ids.len(),
+        &PedersenOptions {
+            secret: secret.clone(),
+            blinder: blinding,
+            secret_generator: share_generator,
+            blinder_generator: blind_factor_generator,
+            participant_generators: &[ParticipantIdGeneratorType::list(ids)],
+            reuse_feldman_commitments: None,
         },
         rng,
     )
 }
+
+/// Check that a Feldman and Pedersen verifier set can describe the same dealing
+/// i.e. that the Pedersen commitments `g * a_i + h * b_i` could contain the
+/// Feldman commitments `g * a_i` as their secret part.
+///
+/// Both sets must use the same secret generator `g`, a blinder generator `h`
+/// that is nonzero and differs from `g`, and have the same number of commitments.
+/// Each difference `pedersen_i - feldman_i = h * b_i` must not be the identity
+/// since the blinder polynomial coefficients are nonzero.
+/// In a prime order group every nonzero difference is a multiple of `h` so no
+/// further check is possible without the blinder; verify shares and blinder
+/// shares against both sets for that.
+///
+/// Returns [`Error::InvalidGenerator`] if the generators are unusable or differ,
+/// [`Error::InvalidCommitmentCount`] if the number of commitments differ and
+/// [`Error::InvalidShare`] if a Pedersen commitment has no blinder part.
+pub fn verify_feldman_pedersen_consistency<S, V>(
+    feldman: &impl FeldmanVerifierSet<S, V>,
+    pedersen: &impl PedersenVerifierSet<S, V>,
+) -> VsssResult<()>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    let g = feldman.generator();
+    let h = pedersen.blinder_generator();
+    if g != pedersen.secret_generator() {
+        return Err(Error::InvalidGenerator(
+            "Feldman and Pedersen secret generators differ",
+        ));
+    }
+    if (g.is_zero() | h.is_zero()).into() || g == h {
+        return Err(Error::InvalidGenerator(
+            "Pedersen generators cannot be zero or the same",
+        ));
+    }
+    let expected = pedersen.blind_verifiers().len();
+    let actual = feldman.verifiers().len();
+    if expected != actual {
+        return Err(Error::InvalidCommitmentCount { expected, actual });
+    }
+    for (p, f) in pedersen.blind_verifiers().iter().zip(feldman.verifiers()) {
+        if (*p - *f).is_zero().into() {
+            return Err(Error::InvalidShare);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Verify a serialized Pedersen dealing is internally consistent.
+///
+/// The Feldman verifier set is the generator followed by the `threshold`
+/// commitments, the Pedersen verifier set is the secret and blinder
+/// generators followed by the `threshold` commitments. Shares are given as
+/// identifier and value byte pairs as accepted by [`Share::from_bytes`].
+///
+/// Every element is decoded with [`ShareElement::from_slice`], the sets are
+/// checked with [`verify_feldman_pedersen_consistency`] then each share is
+/// checked against the Feldman set and with its blinder against the Pedersen set.
+///
+/// Returns [`Error::InvalidCommitmentCount`] if a set has the wrong length,
+/// [`Error::InvalidSizeRequest`] if the number of shares and blinders differ
+/// and [`Error::ShareAtIndexInvalid`] with the index of the first share or
+/// blinder whose identifier doesn't match or that fails verification.
+pub fn verify_pedersen_dealing_bytes<S, V>(
+    threshold: usize,
+    feldman_verifier_set: &[&[u8]],
+    pedersen_verifier_set: &[&[u8]],
+    secret_shares: &[(&[u8], &[u8])],
+    blinder_shares: &[(&[u8], &[u8])],
+) -> VsssResult<()>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    if feldman_verifier_set.len() != threshold + 1 {
+        return Err(Error::InvalidCommitmentCount {
+            expected: threshold + 1,
+            actual: feldman_verifier_set.len(),
+        });
+    }
+    if pedersen_verifier_set.len() != threshold + 2 {
+        return Err(Error::InvalidCommitmentCount {
+            expected: threshold + 2,
+            actual: pedersen_verifier_set.len(),
+        });
+    }
+    if secret_shares.len() != blinder_shares.len() {
+        return Err(Error::InvalidSizeRequest);
+    }
+    let feldman = feldman_verifier_set
+        .iter()
+        .map(|b| V::from_slice(b))
+        .collect::<VsssResult<Vec<V>>>()?;
+    let pedersen = pedersen_verifier_set
+        .iter()
+        .map(|b| V::from_slice(b))
+        .collect::<VsssResult<Vec<V>>>()?;
+    verify_feldman_pedersen_consistency::<S, V>(&feldman, &pedersen)?;
+
+    let mut shares = Vec::with_capacity(secret_shares.len());
+    for (i, ((si, sv), (bi, bv))) in secret_shares.iter().zip(blinder_shares).enumerate() {
+        let share = S::from_bytes(si, sv).map_err(|_| Error::ShareAtIndexInvalid(i))?;
+        let blinder = S::from_bytes(bi, bv).map_err(|_| Error::ShareAtIndexInvalid(i))?;
+        if share.identifier() != blinder.identifier() {
+            return Err(Error::ShareAtIndexInvalid(i));
+        }
+        shares.push((share, blinder));
+    }
+    let ids = shares
+        .iter()
+        .map(|(s, _)| s.identifier().clone())
+        .collect::<Vec<_>>();
+    shamir::check_identifiers(&ids)?;
+    for (i, (share, blinder)) in shares.iter().enumerate() {
+        FeldmanVerifierSet::<S, V>::verify_share(&feldman, share)
+            .and_then(|_| {
+                PedersenVerifierSet::<S, V>::verify_share_and_blinder(&pedersen, share, blinder)
+            })
+            .map_err(|_| Error::ShareAtIndexInvalid(i))?;
+    }
+    Ok(())
+}
@@ -4,6 +4,8 @@
 
 use crate::*;
 use generic_array::{ArrayLength, GenericArray};
+#[cfg(feature = "hybrid-array")]
+use hybrid_array::{Array, ArraySize};
 use rand_core::{CryptoRng, RngCore};
 
 /// The polynomial used for generating the shares
@@ -54,6 +56,63 @@ pub trait Polynomial<S: Share> {
         out
     }
 
+    /// Evaluate the polynomial at `x` using all of its coefficients.
+    ///
+    /// Unused coefficients of fixed size polynomials have zero identifiers
+    /// so they don't change the result. Use [`Polynomial::evaluate`] to only
+    /// use the first `threshold` coefficients.
+    fn evaluate_at(&self, x: &S::Identifier) -> S::Value {
+        let len = self.coefficients().len();
+        if len == 0 {
+            return S::Value::zero();
+        }
+        self.evaluate(x, len)
+    }
+
+    /// Commit to the first `threshold` coefficients of this polynomial
+    /// and `blinder_polynomial` in a single pass.
+    ///
+    /// Returns the feldman commitments `g * a_i` and the pedersen
+    /// commitments `g * a_i + h * b_i`, reusing each feldman commitment
+    /// for the pedersen one.
+    fn commit_dual<V, F, P>(
+        &self,
+        g: V,
+        h: V,
+        blinder_polynomial: &Self,
+        threshold: usize,
+    ) -> VsssResult<(F, P)>
+    where
+        V: ShareVerifier<S>,
+        F: FeldmanVerifierSet<S, V>,
+        P: PedersenVerifierSet<S, V>,
+    {
+        let secret_coefficients = self.coefficients();
+        let blinder_coefficients = blinder_polynomial.coefficients();
+        if threshold == 0
+            || secret_coefficients.len() < threshold
+            || blinder_coefficients.len() < threshold
+        {
+            return Err(Error::InvalidSizeRequest);
+        }
+        let mut feldman_verifier_set = F::empty_feldman_set_with_capacity(threshold, g);
+        let mut pedersen_verifier_set = P::empty_pedersen_set_with_capacity(threshold, g, h);
+        let feldman_verifiers = feldman_verifier_set.verifiers_mut();
+        let pedersen_verifiers = pedersen_verifier_set.blind_verifiers_mut();
+        if feldman_verifiers.len() < threshold || pedersen_verifiers.len() < threshold {
+            return Err(Error::InvalidSizeRequest);
+        }
+
+        feldman_verifiers[0] = g * secret_coefficients[0].value();
+        pedersen_verifiers[0] = feldman_verifiers[0] + h * blinder_coefficients[0].value();
+
+        for i in 1..threshold {
+            feldman_verifiers[i] = g * secret_coefficients[i].identifier();
+            pedersen_verifiers[i] = feldman_verifiers[i] + h * blinder_coefficients[i].identifier();
+        }
+        Ok((feldman_verifier_set, pedersen_verifier_set))
+    }
+
     /// Return the coefficients of the polynomial
     fn coefficients(&self) -> &[S];
 
@@ -89,6 +148,21 @@ impl<S: Share, L: ArrayLength> Polynomial<S> for GenericArray<S, L> {
     }
 }
 
+#[cfg(feature = "hybrid-array")]
+impl<S: Share, L: ArraySize> Polynomial<S> for Array<S, L> {
+    fn create(_size_hint: usize) -> Self {
+        Array::default()
+    }
+
+    fn coefficients(&self) -> &[S] {
+        self.as_ref()
+    }
+
+    fn coefficients_mut(&mut self) -> &mut [S] {
+        self.as_mut()
+    }
+}
+
 #[cfg(any(feature = "alloc", feature = "std"))]
 impl<S: Share> Polynomial<S> for Vec<S> {
     fn create(size_hint: usize) -> Self {
@@ -0,0 +1,47 @@
+//! A 256-bit prime field for secret sharing without an elliptic curve.
+//!
+//! The modulus is the prime 2^255 - 19.
+
+use ff::PrimeField;
+
+/// A prime field element modulo 2^255 - 19.
+#[derive(PrimeField)]
+#[PrimeFieldModulus = "57896044618658097711785492504343953926634992332820282019728792003956564819949"]
+#[PrimeFieldGenerator = "2"]
+#[PrimeFieldReprEndianness = "big"]
+pub struct PrimeFieldU256([u64; 4]);
+
+#[cfg(feature = "zeroize")]
+impl zeroize::DefaultIsZeroes for PrimeFieldU256 {}
+
+#[cfg(all(test, any(feature = "alloc", feature = "std")))]
+mod tests {
+    use super::*;
+    use crate::*;
+    use ff::Field;
+    use rand_chacha::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    type U256Share =
+        DefaultShare<IdentifierPrimeField<PrimeFieldU256>, ValuePrimeField<PrimeFieldU256>>;
+
+    #[test]
+    fn split_combine() {
+        let mut rng = ChaCha8Rng::from_seed([11u8; 32]);
+        let secret = IdentifierPrimeField(PrimeFieldU256::random(&mut rng));
+        let shares = shamir::split_secret::<U256Share>(3, 5, &secret, &mut rng).unwrap();
+
+        let res = shares[..3].to_vec().combine();
+        assert_eq!(res.unwrap(), secret);
+        let res = shares[2..].to_vec().combine();
+        assert_eq!(res.unwrap(), secret);
+    }
+
+    #[test]
+    fn modulus_wraps() {
+        let minus_one = -PrimeFieldU256::ONE;
+        assert_eq!(minus_one + PrimeFieldU256::ONE, PrimeFieldU256::ZERO);
+        let repr = minus_one.to_repr();
+        assert_eq!(PrimeFieldU256::from_repr(repr).unwrap(), minus_one);
+    }
+}
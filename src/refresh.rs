@@ -0,0 +1,192 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+//! Proactive share refresh and resharing.
+//!
+//! Refreshing re-randomizes shares while preserving the secret so
+//! shares compromised before a refresh cannot be combined with
+//! shares compromised after it.
+//!
+//! Resharing hands a secret from one committee and threshold
+//! to another without reconstructing it.
+use crate::*;
+use rand_core::{CryptoRng, RngCore};
+
+/// Refresh `existing` shares by adding a fresh sharing of zero
+/// over the same identifiers with the same `threshold`.
+///
+/// The returned shares keep the identifiers and order of `existing`
+/// and combine to the same secret.
+/// Returns [`Error::SharingInvalidIdentifier`] if an identifier is zero
+/// and [`Error::SharingDuplicateIdentifier`] if one is repeated.
+pub fn refresh_shares<S: Share>(
+    existing: &[S],
+    threshold: usize,
+    rng: impl RngCore + CryptoRng,
+) -> VsssResult<Vec<S>> {
+    check_params(threshold, existing.len())?;
+    let ids = existing
+        .iter()
+        .map(|s| s.identifier().clone())
+        .collect::<Vec<_>>();
+    shamir::check_identifiers(&ids)?;
+    let zero_shares = shamir::split_secret_with_participant_generator::<S>(
+        threshold,
+        ids.len(),
+        &S::Value::zero(),
+        rng,
+        &[ParticipantIdGeneratorType::list(&ids)],
+    )?;
+    Ok(existing
+        .iter()
+        .zip(zero_shares.iter())
+        .map(|(share, zero)| {
+            debug_assert_eq!(share.identifier(), zero.identifier());
+            let mut value = share.value().clone();
+            *value.as_mut() += zero.value().as_ref();
+            S::with_identifier_and_value(share.identifier().clone(), value)
+        })
+        .collect())
+}
+
+/// Reshare a secret held by `old_shares` to the participants produced by
+/// `new_generators` under `new_threshold` without reconstructing it.
+///
+/// Each old share is re-split with [`reshare_share`]. The result holds for
+/// each new participant the sub-shares from every old share in the same
+/// order as `old_shares`. Each new participant turns their sub-shares into
+/// a single share of the original secret with [`combine_reshared`].
+///
+/// At least `old_threshold` old shares are required, otherwise
+/// [`Error::SharingMinThreshold`] is returned.
+/// `new_generators` must produce a finite number of identifiers
+/// which is the size of the new committee.
+pub fn reshare<S: Share>(
+    old_shares: &[S],
+    old_threshold: usize,
+    new_threshold: usize,
+    new_generators: &ParticipantIdGeneratorCollection<S::Identifier>,
+    mut rng: impl RngCore + CryptoRng,
+) -> VsssResult<Vec<Vec<S>>> {
+    if old_threshold < 2 || old_shares.len() < old_threshold {
+        return Err(Error::SharingMinThreshold);
+    }
+    let old_ids = old_shares
+        .iter()
+        .map(|s| s.identifier().clone())
+        .collect::<Vec<_>>();
+    shamir::check_identifiers(&old_ids)?;
+    let new_ids = new_generators.iter().collect::<Vec<_>>();
+
+    let mut sub_shares = vec![Vec::with_capacity(old_shares.len()); new_ids.len()];
+    for share in old_shares {
+        let split = reshare_share(share, new_threshold, &new_ids, &mut rng)?;
+        for (participant, sub_share) in sub_shares.iter_mut().zip(split) {
+            participant.push(sub_share);
+        }
+    }
+    Ok(sub_shares)
+}
+
+/// Reshare a secret held by `current_shares` to `new_ids` under `new_threshold`
+/// and return the new shares with a Feldman verifier set for them.
+///
+/// Each current share is re-split with Feldman commitments. The new shares
+/// and commitments are the lagrange weighted sums of the sub-shares and
+/// commitments so the new verifier set commits to the original secret.
+/// `current_shares` must be a valid quorum of the old sharing.
+/// The verifiers use `generator` or the default generator when `None`.
+pub fn reshare_verifiable<S, V>(
+    current_shares: &[S],
+    new_threshold: usize,
+    new_ids: &[S::Identifier],
+    generator: Option<V>,
+    mut rng: impl RngCore + CryptoRng,
+) -> VsssResult<(Vec<S>, Vec<V>)>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    let old_ids = current_shares
+        .iter()
+        .map(|s| s.identifier().clone())
+        .collect::<Vec<_>>();
+    let lagrange = LagrangeCoefficients::<S>::new(&old_ids)?;
+
+    let mut values = vec![Vec::with_capacity(current_shares.len()); new_ids.len()];
+    let mut verifiers: Vec<V> = Vec::new();
+    for (share, lambda) in current_shares.iter().zip(lagrange.coefficients()) {
+        let (sub_shares, sub_verifiers) = feldman::split_secret_for_ids::<S, V>(
+            new_threshold,
+            share.value(),
+            generator,
+            new_ids,
+            &mut rng,
+        )?;
+        for (participant, sub_share) in values.iter_mut().zip(sub_shares) {
+            participant.push(sub_share.value().clone());
+        }
+        if verifiers.is_empty() {
+            verifiers = vec![V::default(); sub_verifiers.len()];
+            verifiers[0] = sub_verifiers[0];
+        }
+        for (v, c) in verifiers[1..].iter_mut().zip(&sub_verifiers[1..]) {
+            *v += *c * lambda.clone();
+        }
+    }
+
+    let shares = new_ids
+        .iter()
+        .zip(&values)
+        .map(|(id, values)| {
+            lagrange
+                .combine_values(values)
+                .map(|value| S::with_identifier_and_value(id.clone(), value))
+        })
+        .collect::<VsssResult<Vec<_>>>()?;
+    Ok((shares, verifiers))
+}
+
+/// Re-split a single old share's value under `new_threshold`
+/// to the new participants `new_ids`.
+/// This is the step each old share holder runs locally during [`reshare`].
+pub fn reshare_share<S: Share>(
+    share: &S,
+    new_threshold: usize,
+    new_ids: &[S::Identifier],
+    rng: impl RngCore + CryptoRng,
+) -> VsssResult<Vec<S>> {
+    shamir::check_identifiers(new_ids)?;
+    shamir::split_secret_with_participant_generator::<S>(
+        new_threshold,
+        new_ids.len(),
+        share.value(),
+        rng,
+        &[ParticipantIdGeneratorType::list(new_ids)],
+    )
+}
+
+/// Combine a new participant's sub-shares from [`reshare`] into their share
+/// of the original secret.
+///
+/// `old_identifiers` are the identifiers of the old shares
+/// in the same order as `sub_shares`.
+pub fn combine_reshared<S: Share>(
+    old_identifiers: &[S::Identifier],
+    sub_shares: &[S],
+) -> VsssResult<S> {
+    let id = sub_shares
+        .first()
+        .ok_or(Error::SharingMinThreshold)?
+        .identifier();
+    if sub_shares.iter().any(|s| s.identifier() != id) {
+        return Err(Error::SharingInvalidIdentifier);
+    }
+    let values = sub_shares
+        .iter()
+        .map(|s| s.value().clone())
+        .collect::<Vec<_>>();
+    let value = LagrangeCoefficients::<S>::new(old_identifiers)?.combine_values(&values)?;
+    Ok(S::with_identifier_and_value(id.clone(), value))
+}
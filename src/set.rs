@@ -7,7 +7,13 @@ use core::{
     marker::PhantomData,
     ops::{Deref, DerefMut},
 };
+use elliptic_curve::group::Curve;
 use generic_array::{ArrayLength, GenericArray};
+#[cfg(feature = "hybrid-array")]
+use hybrid_array::{Array, ArraySize};
+
+/// The default maximum number of shares [`ReadableShareSet::combine`] will process
+pub const DEFAULT_MAX_COMBINE_SHARES: usize = 65536;
 
 /// Represents a readable data store for secret shares
 pub trait ReadableShareSet<S>: AsRef<[S]>
@@ -16,22 +22,440 @@ where
 {
     /// Convert the given shares into a field element
     fn combine(&self) -> VsssResult<S::Value> {
+        self.combine_with_limit(DEFAULT_MAX_COMBINE_SHARES)
+    }
+
+    /// Convert the given shares into a field element
+    /// refusing to process more than `max_shares` shares
+    fn combine_with_limit(&self, max_shares: usize) -> VsssResult<S::Value> {
         let shares = self.as_ref();
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("combine", share_count = shares.len()).entered();
+        check_combine_shares(shares, max_shares)?;
+        interpolate(shares, &S::Identifier::zero())
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    /// Convert the given shares into a field element using the first
+    /// `threshold` shares whose identifiers are nonzero and whose
+    /// pairwise differences are all invertible, skipping any others.
+    ///
+    /// Returns [`Error::NotEnoughShareIdentifiers`] if no such quorum exists.
+    fn combine_best_quorum(&self, threshold: usize) -> VsssResult<S::Value> {
+        if threshold < 2 {
+            return Err(Error::SharingMinThreshold);
+        }
+        let mut quorum: Vec<S> = Vec::with_capacity(threshold);
+        for share in self.as_ref() {
+            if quorum.len() == threshold {
+                break;
+            }
+            let x_i = share.identifier();
+            if x_i.is_zero().into() {
+                continue;
+            }
+            let invertible = quorum.iter().all(|s| {
+                let d: S::Identifier =
+                    (s.identifier().as_ref().clone() - x_i.as_ref().clone()).into();
+                d.invert().is_ok()
+            });
+            if invertible {
+                quorum.push(share.clone());
+            }
+        }
+        if quorum.len() < threshold {
+            return Err(Error::NotEnoughShareIdentifiers);
+        }
+        quorum.combine()
+    }
+
+    /// Evaluate the polynomial defined by the given shares at `x`
+    /// instead of at zero e.g. to derive a share for a new participant
+    fn combine_to_point(&self, x: &S::Identifier) -> VsssResult<S::Value> {
+        let shares = self.as_ref();
+        check_combine_shares(shares, DEFAULT_MAX_COMBINE_SHARES)?;
+        interpolate(shares, x)
+    }
+
+    /// Convert the given shares into a field element using the first `threshold`
+    /// shares and check every remaining share lies on the same polynomial.
+    ///
+    /// Without verifiers this is the only way to notice a wrong share,
+    /// though it can't tell which share is wrong.
+    /// Returns [`Error::InvalidShare`] if any remaining share disagrees.
+    fn combine_with_error_detection(&self, threshold: usize) -> VsssResult<S::Value> {
+        let shares = self.as_ref();
+        if threshold < 2 {
+            return Err(Error::SharingMinThreshold);
+        }
+        if shares.len() < threshold {
+            return Err(Error::NotEnoughShareIdentifiers);
+        }
+        check_combine_shares(shares, DEFAULT_MAX_COMBINE_SHARES)?;
+        let (quorum, rest) = shares.split_at(threshold);
+        for share in rest {
+            if interpolate(quorum, share.identifier())? != *share.value() {
+                return Err(Error::InvalidShare);
+            }
+        }
+        interpolate(quorum, &S::Identifier::zero())
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    /// Convert the given shares into a field element correcting up to
+    /// `max_errors` wrong shares with Berlekamp-Welch decoding.
+    ///
+    /// Needs at least `threshold + 2 * max_errors` shares and share values
+    /// in the same field as the identifiers. Returns the secret and the indices
+    /// of the shares that were wrong.
+    /// Returns [`Error::NotEnoughShareIdentifiers`] if there are too few shares
+    /// and [`Error::InvalidShare`] if more than `max_errors` shares are wrong.
+    fn combine_with_correction(
+        &self,
+        threshold: usize,
+        max_errors: usize,
+    ) -> VsssResult<(S::Value, Vec<usize>)>
+    where
+        S::Identifier: for<'a> From<&'a S::Value>,
+    {
+        let shares = self.as_ref();
+        if threshold < 2 {
+            return Err(Error::SharingMinThreshold);
+        }
+        if shares.len() < threshold + 2 * max_errors {
+            return Err(Error::NotEnoughShareIdentifiers);
+        }
+        check_combine_shares(shares, DEFAULT_MAX_COMBINE_SHARES)?;
+
+        // Q(x_i) = y_i * E(x_i) where E is monic of degree max_errors and Q
+        // has degree threshold + max_errors - 1. The unknowns are the
+        // coefficients of Q followed by the non leading coefficients of E.
+        let q_len = threshold + max_errors;
+        let unknowns = q_len + max_errors;
+        let mut rows = Vec::with_capacity(shares.len());
+        for share in shares {
+            let x = share.identifier();
+            let y = S::Identifier::from(share.value());
+            let mut row = Vec::with_capacity(unknowns + 1);
+            let mut power = S::Identifier::one();
+            for _ in 0..q_len {
+                row.push(power.clone());
+                *power.as_mut() *= x.as_ref();
+            }
+            let mut power = S::Identifier::one();
+            for _ in 0..max_errors {
+                let mut t = S::Identifier::zero();
+                *t.as_mut() -= y.as_ref();
+                *t.as_mut() *= power.as_ref();
+                row.push(t);
+                *power.as_mut() *= x.as_ref();
+            }
+            // The leading term of E is on the right hand side
+            let mut rhs = y;
+            for _ in 0..max_errors {
+                *rhs.as_mut() *= x.as_ref();
+            }
+            row.push(rhs);
+            rows.push(row);
+        }
+        let solution = solve_linear_system(&mut rows, unknowns).ok_or(Error::InvalidShare)?;
+        let locator = &solution[q_len..];
+
+        // Every wrong share is a root of E so the others are all correct
+        let good = shares
+            .iter()
+            .filter(|share| {
+                let x = share.identifier();
+                let mut e = S::Identifier::zero();
+                let mut power = S::Identifier::one();
+                for c in locator {
+                    let mut t = c.clone();
+                    *t.as_mut() *= power.as_ref();
+                    *e.as_mut() += t.as_ref();
+                    *power.as_mut() *= x.as_ref();
+                }
+                *e.as_mut() += power.as_ref();
+                !bool::from(e.is_zero())
+            })
+            .take(threshold)
+            .cloned()
+            .collect::<Vec<_>>();
+        if good.len() < threshold {
+            return Err(Error::InvalidShare);
+        }
+        let mut errors = Vec::new();
+        for (i, share) in shares.iter().enumerate() {
+            if interpolate(&good, share.identifier())? != *share.value() {
+                errors.push(i);
+            }
+        }
+        if errors.len() > max_errors {
+            return Err(Error::InvalidShare);
+        }
+        Ok((interpolate(&good, &S::Identifier::zero())?, errors))
+    }
+
+    /// Convert the given shares into a field element like [`ReadableShareSet::combine`]
+    /// but copying identifiers and values instead of cloning them
+    /// for share types where both are [`Copy`].
+    fn combine_copy(&self) -> VsssResult<S::Value>
+    where
+        S::Identifier: Copy,
+        S::Value: Copy,
+        <S::Identifier as ShareElement>::Inner: Copy,
+    {
+        let shares = self.as_ref();
+        check_combine_shares(shares, DEFAULT_MAX_COMBINE_SHARES)?;
+        let mut secret = S::Value::default();
+        for (i, share) in shares.iter().enumerate() {
+            let x_i = *share.identifier().as_ref();
+            let mut num = Scrub(S::Identifier::one());
+            let mut den = Scrub(S::Identifier::one());
+            for (j, other) in shares.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                let x_j = *other.identifier().as_ref();
+                *den.as_mut() *= x_j - x_i;
+                *num.as_mut() *= x_j;
+            }
+            let den = Scrub(den.invert()?);
+            *num.as_mut() *= den.as_ref();
+            let t = Scrub(*share.value() * &*num);
+            *secret.as_mut() += t.as_ref();
+        }
+        Ok(secret)
+    }
+
+    /// Convert the given shares into a field element
+    /// returning [`Error::InvalidSecret`] if the result is zero
+    /// since a zero secret is never a valid key
+    fn combine_nonzero(&self) -> VsssResult<S::Value> {
+        let secret = self.combine()?;
+        if secret.is_zero().into() {
+            return Err(Error::InvalidSecret);
+        }
+        Ok(secret)
+    }
+
+    /// Convert the first `count` shares of this padded buffer into a field element
+    /// in constant time with respect to `count` and `threshold`.
+    ///
+    /// Every slot in the buffer is processed regardless of `count` so
+    /// attempting to combine fewer than `threshold` shares takes the same
+    /// time as combining enough. The result is none if fewer than `threshold`
+    /// or `2` shares are present, `count` exceeds the buffer length, or
+    /// the present shares have zero or duplicate identifiers.
+    fn combine_ct(&self, count: usize, threshold: usize) -> CtOption<S::Value>
+    where
+        <S::Identifier as ShareElement>::Inner: ConditionallySelectable,
+        <S::Value as ShareElement>::Inner: ConditionallySelectable,
+    {
+        let shares = self.as_ref();
+        let count = count as u64;
+        let threshold = threshold as u64;
+        let mut valid =
+            !count.ct_lt(&threshold) & !count.ct_lt(&2) & !(shares.len() as u64).ct_lt(&count);
+
+        let one = S::Identifier::one();
+        let mut secret = S::Value::default();
+        for (i, x_i) in shares.iter().enumerate() {
+            let active_i = (i as u64).ct_lt(&count);
+            let mut num = S::Identifier::one();
+            let mut den = S::Identifier::one();
+            for (j, x_j) in shares.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                let active_j = (j as u64).ct_lt(&count);
+                // x_j / (x_j - x_i) * ... for present shares only
+                let n = x_j.identifier().as_ref();
+                let d = *n - *x_i.identifier().as_ref();
+                *num.as_mut() *=
+                    ConditionallySelectable::conditional_select(one.as_ref(), n, active_j);
+                *den.as_mut() *=
+                    ConditionallySelectable::conditional_select(one.as_ref(), &d, active_j);
+            }
+            let den_is_zero = den.is_zero();
+            valid &= !(active_i & (x_i.identifier().is_zero() | den_is_zero));
+            let den = S::Identifier::from(ConditionallySelectable::conditional_select(
+                den.as_ref(),
+                one.as_ref(),
+                den_is_zero,
+            ));
+            let inv = den.invert().unwrap_or_else(|_| {
+                valid = Choice::from(0);
+                S::Identifier::one()
+            });
+            *num.as_mut() *= inv.as_ref();
+            let t = x_i.value().clone() * &num;
+            let mut term = S::Value::default();
+            term.as_mut().conditional_assign(t.as_ref(), active_i);
+            *secret.as_mut() += term.as_ref();
+        }
+        CtOption::new(secret, valid)
+    }
+
+    /// Convert the given shares into a field element without branching
+    /// on the identifier values.
+    ///
+    /// Unlike [`ReadableShareSet::combine`] which skips terms and stops at
+    /// the first zero or duplicate identifier, this always performs the
+    /// full quadratic interpolation with [`ReadableShareSet::combine_ct`]
+    /// using every share, so the time taken depends only on the number of
+    /// shares. This makes it slower than [`ReadableShareSet::combine`].
+    fn combine_constant_time(&self) -> VsssResult<S::Value>
+    where
+        <S::Identifier as ShareElement>::Inner: ConditionallySelectable,
+        <S::Value as ShareElement>::Inner: ConditionallySelectable,
+    {
+        let shares = self.as_ref();
+        if shares.len() > DEFAULT_MAX_COMBINE_SHARES {
+            return Err(Error::InvalidSizeRequest);
+        }
         if shares.len() < 2 {
             return Err(Error::SharingMinThreshold);
         }
-        for s in shares {
-            if s.identifier().is_zero().into() {
-                return Err(Error::SharingInvalidIdentifier);
+        Option::from(self.combine_ct(shares.len(), shares.len())).ok_or_else(|| {
+            if shares.iter().any(|s| s.identifier().is_zero().into()) {
+                Error::SharingInvalidIdentifier
+            } else {
+                Error::SharingDuplicateIdentifier
             }
+        })
+    }
+
+    /// Convert the given group valued shares into a group element.
+    ///
+    /// This is [`ReadableShareSet::combine`] with the result normalized
+    /// through affine coordinates, so curves whose projective points have
+    /// many representations return a canonical one.
+    fn combine_projective<G>(&self) -> VsssResult<ValueGroup<G>>
+    where
+        S: Share<Value = ValueGroup<G>>,
+        G: Group + GroupEncoding + Default + Curve + From<<G as Curve>::AffineRepr>,
+    {
+        let secret = self.combine()?;
+        Ok(ValueGroup(G::from(secret.0.to_affine())))
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    /// Convert the given shares into a field element
+    /// reusing the buffers in `scratch` across calls.
+    ///
+    /// The lagrange denominators are inverted together
+    /// so only a single inversion is needed per call.
+    fn combine_with_scratch(&self, scratch: &mut CombineScratch<S>) -> VsssResult<S::Value> {
+        let shares = self.as_ref();
+        check_combine_shares(shares, DEFAULT_MAX_COMBINE_SHARES)?;
+        scratch.denominators.clear();
+        scratch.products.clear();
+
+        for (i, x_i) in shares.iter().enumerate() {
+            let mut den = S::Identifier::one();
+            for (j, x_j) in shares.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                // (x_j - x_i) * ...
+                let d = x_j.identifier().as_ref().clone() - x_i.identifier().as_ref().clone();
+                *den.as_mut() *= d;
+            }
+            let mut product = scratch
+                .products
+                .last()
+                .cloned()
+                .unwrap_or_else(S::Identifier::one);
+            *product.as_mut() *= den.as_ref();
+            scratch.denominators.push(den);
+            scratch.products.push(product);
+        }
+
+        // Batch invert the denominators in place
+        let mut inv = scratch.products[shares.len() - 1].invert()?;
+        for i in (1..shares.len()).rev() {
+            let mut inv_i = inv.clone();
+            *inv_i.as_mut() *= scratch.products[i - 1].as_ref();
+            *inv.as_mut() *= scratch.denominators[i].as_ref();
+            scratch.denominators[i] = inv_i;
+        }
+        scratch.denominators[0] = inv;
+
+        let mut secret = S::Value::default();
+        for (i, (x_i, inv_den)) in shares.iter().zip(&scratch.denominators).enumerate() {
+            let mut basis = inv_den.clone();
+            for (j, x_j) in shares.iter().enumerate() {
+                if i != j {
+                    *basis.as_mut() *= x_j.identifier().as_ref();
+                }
+            }
+            let t = x_i.value().clone() * &basis;
+            *secret.as_mut() += t.as_ref();
+        }
+        Ok(secret)
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Reusable buffers for [`ReadableShareSet::combine_with_scratch`]
+/// so repeated reconstructions do not allocate on every call.
+#[derive(Debug, Clone)]
+pub struct CombineScratch<S: Share> {
+    denominators: Vec<S::Identifier>,
+    products: Vec<S::Identifier>,
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<S: Share> Default for CombineScratch<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<S: Share> CombineScratch<S> {
+    /// Create empty scratch buffers
+    pub fn new() -> Self {
+        Self {
+            denominators: Vec::new(),
+            products: Vec::new(),
         }
-        if dup_checker(shares) {
-            return Err(Error::SharingDuplicateIdentifier);
+    }
+
+    /// Create scratch buffers able to combine `shares` shares without growing
+    pub fn with_capacity(shares: usize) -> Self {
+        Self {
+            denominators: Vec::with_capacity(shares),
+            products: Vec::with_capacity(shares),
         }
-        interpolate(shares)
+    }
+
+    /// The number of shares that can be combined without growing the buffers
+    pub fn capacity(&self) -> usize {
+        self.denominators.capacity().min(self.products.capacity())
     }
 }
 
+fn check_combine_shares<S>(shares: &[S], max_shares: usize) -> VsssResult<()>
+where
+    S: Share,
+{
+    if shares.len() > max_shares {
+        return Err(Error::InvalidSizeRequest);
+    }
+    if shares.len() < 2 {
+        return Err(Error::SharingMinThreshold);
+    }
+    for s in shares {
+        s.try_identifier()
+            .map_err(|_| Error::SharingInvalidIdentifier)?;
+    }
+    if dup_checker(shares) {
+        return Err(Error::SharingDuplicateIdentifier);
+    }
+    Ok(())
+}
+
 /// Represents a data store for secret shares
 pub trait WriteableShareSet<S>: ReadableShareSet<S> + AsMut<[S]>
 where
@@ -43,33 +467,324 @@ where
 
 impl<S, B: AsRef<[S]>> ReadableShareSet<S> for B where S: Share {}
 
-fn interpolate<S>(shares: &[S]) -> VsssResult<S::Value>
+fn interpolate<S>(shares: &[S], x: &S::Identifier) -> VsssResult<S::Value>
 where
     S: Share,
 {
     let mut secret = S::Value::default();
     // Calculate lagrange interpolation
-    for (i, x_i) in shares.iter().enumerate() {
-        let mut num = S::Identifier::one();
-        let mut den = S::Identifier::one();
-        for (j, x_j) in shares.iter().enumerate() {
-            if i == j {
+    for i in 0..shares.len() {
+        let t = Scrub(lagrange_term(shares, i, x));
+        *secret.as_mut() += t.as_ref();
+    }
+
+    Ok(secret)
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Solve the augmented system `rows` in place by gauss-jordan elimination
+/// setting any free unknowns to zero. Returns `None` if it is inconsistent.
+fn solve_linear_system<I: ShareIdentifier>(rows: &mut [Vec<I>], unknowns: usize) -> Option<Vec<I>> {
+    let mut pivots = Vec::with_capacity(unknowns);
+    for col in 0..unknowns {
+        let r = pivots.len();
+        let Some(p) = (r..rows.len()).find(|&p| !bool::from(rows[p][col].is_zero())) else {
+            continue;
+        };
+        rows.swap(r, p);
+        let inv = rows[r][col].invert().ok()?;
+        for v in rows[r].iter_mut() {
+            *v.as_mut() *= inv.as_ref();
+        }
+        let pivot = rows[r].clone();
+        for (other, row) in rows.iter_mut().enumerate() {
+            if other == r || bool::from(row[col].is_zero()) {
                 continue;
             }
+            let factor = row[col].clone();
+            for (v, p) in row[col..].iter_mut().zip(&pivot[col..]) {
+                let mut t = p.clone();
+                *t.as_mut() *= factor.as_ref();
+                *v.as_mut() -= t.as_ref();
+            }
+        }
+        pivots.push(col);
+    }
+    if rows[pivots.len()..]
+        .iter()
+        .any(|row| !bool::from(row[unknowns].is_zero()))
+    {
+        return None;
+    }
+    let mut solution = vec![I::zero(); unknowns];
+    for (r, col) in pivots.into_iter().enumerate() {
+        solution[col] = rows[r][unknowns].clone();
+    }
+    Some(solution)
+}
 
-            // x_j / (x_j - x_i) * ...
-            let d = x_j.identifier().as_ref().clone() - x_i.identifier().as_ref().clone();
-            *den.as_mut() *= d;
-            *num.as_mut() *= x_j.identifier().as_ref();
+fn lagrange_term<S>(shares: &[S], i: usize, x: &S::Identifier) -> S::Value
+where
+    S: Share,
+{
+    let basis = Scrub(lagrange_basis(
+        shares.len(),
+        |j| shares[j].identifier(),
+        i,
+        x,
+    ));
+    shares[i].value().clone() * &*basis
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// The coefficients of the `i`th lagrange basis polynomial over `len`
+/// identifiers, lowest degree first
+pub(crate) fn lagrange_basis_coefficients<'a, I>(
+    len: usize,
+    identifier: impl Fn(usize) -> &'a I,
+    i: usize,
+) -> VsssResult<Vec<I>>
+where
+    I: ShareIdentifier + 'a,
+{
+    let x_i = identifier(i);
+    let mut basis = vec![I::zero(); len];
+    basis[0] = I::one();
+    let mut den = I::one();
+    let mut degree = 0;
+    for j in 0..len {
+        if i == j {
+            continue;
+        }
+        let x_j = identifier(j);
+        // basis *= (x - x_j)
+        degree += 1;
+        for k in (0..=degree).rev() {
+            let mut term = basis[k].clone();
+            *term.as_mut() *= x_j.as_ref();
+            let prev = if k > 0 {
+                basis[k - 1].as_ref().clone()
+            } else {
+                I::zero().as_ref().clone()
+            };
+            basis[k] = (prev - term.as_ref().clone()).into();
         }
+        // den *= (x_i - x_j)
+        let d = x_i.as_ref().clone() - x_j.as_ref().clone();
+        *den.as_mut() *= d;
+    }
+    let den = den.invert()?;
+    for b in basis.iter_mut() {
+        *b.as_mut() *= den.as_ref();
+    }
+    Ok(basis)
+}
 
-        let den = den.invert().expect("shouldn't be zero");
-        let basis: S::Identifier = (num.as_ref().clone() * den.as_ref()).into();
-        let t = x_i.value().clone() * &basis;
-        *secret.as_mut() += t.as_ref();
+/// The `i`th lagrange basis polynomial over `len` identifiers evaluated at `x`
+pub(crate) fn lagrange_basis<'a, I>(
+    len: usize,
+    identifier: impl Fn(usize) -> &'a I,
+    i: usize,
+    x: &I,
+) -> I
+where
+    I: ShareIdentifier + 'a,
+{
+    let x_i = identifier(i);
+    let mut num = Scrub(I::one());
+    let mut den = Scrub(I::one());
+    for j in 0..len {
+        if i == j {
+            continue;
+        }
+        let x_j = identifier(j);
+
+        // (x_j - x) / (x_j - x_i) * ...
+        let d = Scrub(x_j.as_ref().clone() - x_i.as_ref().clone());
+        *den.as_mut() *= &*d;
+        let n = Scrub(x_j.as_ref().clone() - x.as_ref().clone());
+        *num.as_mut() *= &*n;
     }
 
-    Ok(secret)
+    let den = Scrub(den.invert().expect("shouldn't be zero"));
+    (num.as_ref().clone() * den.as_ref()).into()
+}
+
+/// Evaluate the commitment polynomial `c_0 * c_1^x * c_2^{x^2} ... c_t^{x^t}`.
+///
+/// Only public values are involved so the simultaneous multiplication
+/// is allowed to branch on the bits of the powers of `x`.
+pub(crate) fn evaluate_commitments<S, G>(commitments: &[G], x: &S::Identifier) -> G
+where
+    S: Share,
+    G: ShareVerifier<S>,
+{
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    if let Some(rhs) = evaluate_commitments_straus::<S, G>(commitments, x) {
+        return rhs;
+    }
+    evaluate_commitments_naive::<S, G>(commitments, x)
+}
+
+fn evaluate_commitments_naive<S, G>(commitments: &[G], x: &S::Identifier) -> G
+where
+    S: Share,
+    G: ShareVerifier<S>,
+{
+    let Some((c_0, commitments)) = commitments.split_first() else {
+        return G::default();
+    };
+    let mut i = S::Identifier::one();
+    let mut rhs = *c_0;
+    for v in commitments {
+        *i.as_mut() *= x.as_ref();
+        rhs += *v * i.clone();
+    }
+    rhs
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+const STRAUS_WINDOW: usize = 4;
+#[cfg(any(feature = "alloc", feature = "std"))]
+const STRAUS_TABLE_SIZE: usize = 1 << STRAUS_WINDOW;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Straus' simultaneous multiple point multiplication,
+/// Guide to Elliptic Curve Cryptography "Algorithm 3.51",
+/// using 4-bit windows so the doublings are shared by all commitments.
+///
+/// Returns `None` if the identifier serialization is not a plain
+/// big or little endian integer so its bits cannot be read.
+fn evaluate_commitments_straus<S, G>(commitments: &[G], x: &S::Identifier) -> Option<G>
+where
+    S: Share,
+    G: ShareVerifier<S>,
+{
+    evaluate_commitments_with_tables::<S, G>(&straus_tables::<S, G>(commitments), x)
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// `[0, P, 2P, ..., 15P]` for each commitment `P`
+/// which only depend on the commitments and can be reused for every identifier.
+fn straus_tables<S, G>(commitments: &[G]) -> Vec<[G; STRAUS_TABLE_SIZE]>
+where
+    S: Share,
+    G: ShareVerifier<S>,
+{
+    commitments
+        .iter()
+        .map(|c| {
+            let mut table = [G::default(); STRAUS_TABLE_SIZE];
+            for w in 1..STRAUS_TABLE_SIZE {
+                table[w] = table[w - 1] + *c;
+            }
+            table
+        })
+        .collect()
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+fn evaluate_commitments_with_tables<S, G>(
+    tables: &[[G; STRAUS_TABLE_SIZE]],
+    x: &S::Identifier,
+) -> Option<G>
+where
+    S: Share,
+    G: ShareVerifier<S>,
+{
+    let one = S::Identifier::one().serialize();
+    let one = one.as_ref();
+    let big_endian = match (one.first(), one.last()) {
+        (_, _) if one.len() == 1 => false,
+        (Some(1), _) if one[1..].iter().all(|b| *b == 0) => false,
+        (_, Some(1)) if one[..one.len() - 1].iter().all(|b| *b == 0) => true,
+        _ => return None,
+    };
+
+    // Scalars as little endian bytes
+    let mut i = S::Identifier::one();
+    let mut scalars = Vec::with_capacity(tables.len());
+    for k in 0..tables.len() {
+        if k > 0 {
+            *i.as_mut() *= x.as_ref();
+        }
+        let mut bytes = i.serialize().as_ref().to_vec();
+        if bytes.len() != one.len() {
+            return None;
+        }
+        if big_endian {
+            bytes.reverse();
+        }
+        scalars.push(bytes);
+    }
+
+    let mut rhs = G::default();
+    for byte in (0..one.len()).rev() {
+        for shift in [STRAUS_WINDOW, 0] {
+            for _ in 0..STRAUS_WINDOW {
+                rhs += rhs;
+            }
+            for (table, scalar) in tables.iter().zip(&scalars) {
+                let w = (scalar[byte] >> shift) as usize & (STRAUS_TABLE_SIZE - 1);
+                if w != 0 {
+                    rhs += table[w];
+                }
+            }
+        }
+    }
+    Some(rhs)
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Lagrange basis coefficients precomputed for a fixed set of identifiers
+/// so many secrets shared over the same identifiers can be combined
+/// without recomputing the coefficients each time.
+#[derive(Debug, Clone)]
+pub struct LagrangeCoefficients<S: Share> {
+    identifiers: Vec<S::Identifier>,
+    coefficients: Vec<S::Identifier>,
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<S: Share> LagrangeCoefficients<S> {
+    /// Precompute the coefficients for interpolating at zero over `identifiers`
+    pub fn new(identifiers: &[S::Identifier]) -> VsssResult<Self> {
+        if identifiers.len() < 2 {
+            return Err(Error::SharingMinThreshold);
+        }
+        shamir::check_identifiers(identifiers)?;
+        let zero = S::Identifier::zero();
+        let coefficients = (0..identifiers.len())
+            .map(|i| lagrange_basis(identifiers.len(), |j| &identifiers[j], i, &zero))
+            .collect();
+        Ok(Self {
+            identifiers: identifiers.to_vec(),
+            coefficients,
+        })
+    }
+
+    /// The identifiers the coefficients were computed for
+    pub fn identifiers(&self) -> &[S::Identifier] {
+        &self.identifiers
+    }
+
+    /// The lagrange coefficients in the same order as the identifiers
+    pub fn coefficients(&self) -> &[S::Identifier] {
+        &self.coefficients
+    }
+
+    /// Combine share values given in the same order as the identifiers
+    pub fn combine_values(&self, values: &[S::Value]) -> VsssResult<S::Value> {
+        if values.len() != self.coefficients.len() {
+            return Err(Error::InvalidSizeRequest);
+        }
+        let mut secret = S::Value::default();
+        for (value, basis) in values.iter().zip(&self.coefficients) {
+            let t = value.clone() * basis;
+            *secret.as_mut() += t.as_ref();
+        }
+        Ok(secret)
+    }
 }
 
 impl<S, const L: usize> WriteableShareSet<S> for [S; L]
@@ -91,6 +806,17 @@ where
     }
 }
 
+#[cfg(feature = "hybrid-array")]
+impl<S, L> WriteableShareSet<S> for Array<S, L>
+where
+    S: Share,
+    L: ArraySize,
+{
+    fn create(_size_hint: usize) -> Self {
+        Self::from_fn(|_| S::default())
+    }
+}
+
 #[cfg(any(feature = "alloc", feature = "std"))]
 impl<S> WriteableShareSet<S> for Vec<S>
 where
@@ -125,11 +851,17 @@ where
     /// Create a new verifier set
     fn empty_feldman_set_with_capacity(size_hint: usize, generator: G) -> Self;
 
-    /// Create a verifier set from an existing set of verifiers and generator
-    fn feldman_set_with_generator_and_verifiers(generator: G, verifiers: &[G]) -> Self {
+    /// Create a verifier set from an existing set of verifiers and generator.
+    /// Returns [`Error::InvalidSizeRequest`] if the set cannot hold exactly `verifiers`.
+    #[must_use = "the verifier set is only valid if the result is Ok"]
+    fn feldman_set_with_generator_and_verifiers(generator: G, verifiers: &[G]) -> VsssResult<Self> {
         let mut set = Self::empty_feldman_set_with_capacity(verifiers.len(), generator);
-        set.verifiers_mut().copy_from_slice(verifiers);
-        set
+        let inner = set.verifiers_mut();
+        if inner.len() != verifiers.len() {
+            return Err(Error::InvalidSizeRequest);
+        }
+        inner.copy_from_slice(verifiers);
+        Ok(set)
     }
 
     /// The generator used for the verifiers
@@ -141,37 +873,136 @@ where
     /// The verifiers as writeable
     fn verifiers_mut(&mut self) -> &mut [G];
 
+    /// The public key for the shared secret, `generator() * secret`.
+    ///
+    /// This is the commitment to the secret, the first of [`Self::verifiers`],
+    /// and not the [`Self::generator`] stored alongside them.
+    /// Returns [`Error::InvalidGenerator`] if the set has no verifiers.
+    fn public_key(&self) -> VsssResult<G> {
+        self.verifiers()
+            .first()
+            .copied()
+            .ok_or(Error::InvalidGenerator("verifier set is empty"))
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    /// Reconstruct the verifier set from `threshold` public shares
+    /// where each point is the share's value multiplied by `generator`.
+    ///
+    /// Each commitment is recovered by interpolating the lagrange
+    /// basis polynomial coefficients in the group.
+    fn reconstruct_from_public_shares(
+        generator: G,
+        points: &[(S::Identifier, G)],
+    ) -> VsssResult<Self> {
+        if points.len() < 2 {
+            return Err(Error::SharingMinThreshold);
+        }
+        if generator.is_zero().into() {
+            return Err(Error::InvalidGenerator("Generator is identity"));
+        }
+        for (i, (x_i, _)) in points.iter().enumerate() {
+            if x_i.is_zero().into() {
+                return Err(Error::SharingInvalidIdentifier);
+            }
+            if points[i + 1..].iter().any(|(x_j, _)| x_j == x_i) {
+                return Err(Error::SharingDuplicateIdentifier);
+            }
+        }
+        let mut set = Self::empty_feldman_set_with_capacity(points.len(), generator);
+        let verifiers = set.verifiers_mut();
+        if verifiers.len() != points.len() {
+            return Err(Error::InvalidSizeRequest);
+        }
+        verifiers.iter_mut().for_each(|v| *v = G::zero());
+
+        for (i, (_, p_i)) in points.iter().enumerate() {
+            let basis = lagrange_basis_coefficients(points.len(), |j| &points[j].0, i)?;
+            for (v, b) in verifiers.iter_mut().zip(basis.iter()) {
+                *v += *p_i * b;
+            }
+        }
+        Ok(set)
+    }
+
     /// Verify a share with this set
     fn verify_share(&self, share: &S) -> VsssResult<()> {
-        if (share.value().is_zero() | share.identifier().is_zero()).into() {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "verify_share",
+            scheme = "feldman",
+            threshold = self.verifiers().len()
+        )
+        .entered();
+        self.verify_value_at(share.try_value()?, share.try_identifier()?)
+    }
+
+    /// Verify every share in `shares` with this set.
+    /// Returns [`Error::ShareAtIndexInvalid`] with the index of the
+    /// first share that does not verify.
+    fn verify_shares(&self, shares: &[S]) -> VsssResult<()> {
+        for (index, s) in shares.iter().enumerate() {
+            self.verify_share(s)
+                .map_err(|_| Error::ShareAtIndexInvalid(index))?;
+        }
+        Ok(())
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    /// Find the shares that do not verify with this set.
+    ///
+    /// Malformed shares, such as those with a zero identifier or value,
+    /// are reported as invalid.
+    fn find_invalid_shares<'a>(&self, shares: &'a [S]) -> Vec<&'a S> {
+        shares
+            .iter()
+            .filter(|s| self.verify_share(s).is_err())
+            .collect()
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    /// Verify every share in `shares` with this set returning whether
+    /// the share at each index is valid, the same as calling
+    /// [`FeldmanVerifierSet::verify_share`] on each one.
+    ///
+    /// The commitment multiples are precomputed once and reused
+    /// for every share instead of being rebuilt per share.
+    fn verify_batch_indexed(&self, shares: &[S]) -> Vec<bool> {
+        if self.verifiers().is_empty() || self.generator().is_zero().into() {
+            return vec![false; shares.len()];
+        }
+        let tables = straus_tables::<S, G>(self.verifiers());
+        shares
+            .iter()
+            .map(|share| {
+                let (id, value) = (share.identifier(), share.value());
+                if (value.is_zero() | id.is_zero()).into() {
+                    return false;
+                }
+                let rhs = evaluate_commitments_with_tables::<S, G>(&tables, id)
+                    .unwrap_or_else(|| evaluate_commitments_naive::<S, G>(self.verifiers(), id));
+                let res: G = rhs - self.generator() * value;
+                res.is_zero().into()
+            })
+            .collect()
+    }
+
+    /// Verify a share value with this set at the specified identifier
+    fn verify_value_at(&self, value: &S::Value, id: &S::Identifier) -> VsssResult<()> {
+        if self.verifiers().is_empty() {
+            return Err(Error::InvalidGenerator("verifier set is empty"));
+        }
+        if (value.is_zero() | id.is_zero()).into() {
             return Err(Error::InvalidShare);
         }
         if self.generator().is_zero().into() {
             return Err(Error::InvalidGenerator("Generator is identity"));
         }
 
-        let s = share.value();
-
-        let mut i = S::Identifier::one();
-
-        // FUTURE: execute this sum of products
         // c_0 * c_1^i * c_2^{i^2} ... c_t^{i^t}
-        // as a constant time operation using <https://cr.yp.to/papers/pippenger.pdf>
-        // or Guide to Elliptic Curve Cryptography book,
-        // "Algorithm 3.48 Simultaneous multiple point multiplication"
-        // without precomputing the addition but still reduces doublings
+        let rhs = evaluate_commitments::<S, G>(self.verifiers(), id);
 
-        // c_0
-        let commitments = self.verifiers();
-        let mut rhs = commitments[0];
-        for v in &commitments[1..] {
-            *i.as_mut() *= share.identifier().as_ref();
-
-            // c_0 * c_1^i * c_2^{i^2} ... c_t^{i^t}
-            rhs += *v * i.clone();
-        }
-
-        let lhs = self.generator() * s;
+        let lhs = self.generator() * value;
 
         let res: G = rhs - lhs;
 
@@ -197,19 +1028,25 @@ where
         blinder_generator: G,
     ) -> Self;
 
-    /// Create a verifier set from an existing set of verifiers and generators
+    /// Create a verifier set from an existing set of verifiers and generators.
+    /// Returns [`Error::InvalidSizeRequest`] if the set cannot hold exactly `verifiers`.
+    #[must_use = "the verifier set is only valid if the result is Ok"]
     fn pedersen_set_with_generators_and_verifiers(
         secret_generator: G,
         blinder_generator: G,
         verifiers: &[G],
-    ) -> Self {
+    ) -> VsssResult<Self> {
         let mut set = Self::empty_pedersen_set_with_capacity(
             verifiers.len(),
             secret_generator,
             blinder_generator,
         );
-        set.blind_verifiers_mut().copy_from_slice(verifiers);
-        set
+        let inner = set.blind_verifiers_mut();
+        if inner.len() != verifiers.len() {
+            return Err(Error::InvalidSizeRequest);
+        }
+        inner.copy_from_slice(verifiers);
+        Ok(set)
     }
 
     /// The generator used for the verifiers of secrets
@@ -226,11 +1063,16 @@ where
 
     /// Verify a share and blinder with this set
     fn verify_share_and_blinder(&self, share: &S, blinder: &S) -> VsssResult<()> {
-        if (share.value().is_zero() | blinder.value().is_zero() | share.identifier().is_zero())
-            .into()
-        {
-            return Err(Error::InvalidShare);
-        }
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "verify_share",
+            scheme = "pedersen",
+            threshold = self.blind_verifiers().len()
+        )
+        .entered();
+        let secret = share.try_value()?;
+        let blinder = blinder.try_value()?;
+        let x = share.try_identifier()?;
         let blind_generator = self.blinder_generator();
         let generator = self.secret_generator();
 
@@ -240,28 +1082,8 @@ where
             ));
         }
 
-        let secret = share.value();
-        let blinder = blinder.value();
-        let x = share.identifier();
-
-        let mut i = S::Identifier::one();
-
-        // FUTURE: execute this sum of products
         // c_0 * c_1^i * c_2^{i^2} ... c_t^{i^t}
-        // as a constant time operation using <https://cr.yp.to/papers/pippenger.pdf>
-        // or Guide to Elliptic Curve Cryptography book,
-        // "Algorithm 3.48 Simultaneous multiple point multiplication"
-        // without precomputing the addition but still reduces doublings
-
-        let commitments = self.blind_verifiers();
-        // c_0
-        let mut rhs = commitments[0];
-        for v in &commitments[1..] {
-            *i.as_mut() *= x.as_ref();
-
-            // c_0 * c_1^i * c_2^{i^2} ... c_t^{i^t}
-            rhs += *v * i.clone();
-        }
+        let rhs = evaluate_commitments::<S, G>(self.blind_verifiers(), x);
 
         let g: G = generator * secret;
         let h: G = blind_generator * blinder;
@@ -274,6 +1096,28 @@ where
             Err(Error::InvalidShare)
         }
     }
+
+    /// Verify a reconstructed secret and blinder against the
+    /// commitment to the constant term `g * secret + h * blinder`.
+    fn verify_reconstructed(&self, secret: &S::Value, blinder: &S::Value) -> VsssResult<()> {
+        let Some(commitment) = self.blind_verifiers().first() else {
+            return Err(Error::InvalidGenerator("verifier set is empty"));
+        };
+        let generator = self.secret_generator();
+        let blind_generator = self.blinder_generator();
+        if generator == G::default() || blind_generator == G::default() {
+            return Err(Error::InvalidGenerator(
+                "Generator or Blind generator is an identity",
+            ));
+        }
+
+        let res = *commitment - generator * secret - blind_generator * blinder;
+        if res == G::default() {
+            Ok(())
+        } else {
+            Err(Error::InvalidSecret)
+        }
+    }
 }
 
 impl<S: Share, G: ShareVerifier<S>, const L: usize> FeldmanVerifierSet<S, G> for [G; L] {
@@ -318,6 +1162,27 @@ impl<S: Share, G: ShareVerifier<S>, L: ArrayLength> FeldmanVerifierSet<S, G>
     }
 }
 
+#[cfg(feature = "hybrid-array")]
+impl<S: Share, G: ShareVerifier<S>, L: ArraySize> FeldmanVerifierSet<S, G> for Array<G, L> {
+    fn empty_feldman_set_with_capacity(_size_hint: usize, generator: G) -> Self {
+        let mut t = Self::default();
+        t[0] = generator;
+        t
+    }
+
+    fn generator(&self) -> G {
+        self[0]
+    }
+
+    fn verifiers(&self) -> &[G] {
+        &self[1..]
+    }
+
+    fn verifiers_mut(&mut self) -> &mut [G] {
+        self[1..].as_mut()
+    }
+}
+
 /// A wrapper around a fixed size array of verifiers
 /// Allows for convenient type aliasing
 /// ```
@@ -421,6 +1286,17 @@ where
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, S, V, const L: usize> arbitrary::Arbitrary<'a> for ArrayFeldmanVerifierSet<S, V, L>
+where
+    S: Share,
+    V: ShareVerifier<S> + arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from(<[V; L]>::arbitrary(u)?))
+    }
+}
+
 impl<S, V, const L: usize> FeldmanVerifierSet<S, V> for ArrayFeldmanVerifierSet<S, V, L>
 where
     S: Share,
@@ -560,6 +1436,22 @@ where
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, S, V, L> arbitrary::Arbitrary<'a> for GenericArrayFeldmanVerifierSet<S, V, L>
+where
+    S: Share,
+    V: ShareVerifier<S> + arbitrary::Arbitrary<'a>,
+    L: ArrayLength,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut inner = GenericArray::<V, L>::default();
+        for v in inner.iter_mut() {
+            *v = V::arbitrary(u)?;
+        }
+        Ok(Self::from(inner))
+    }
+}
+
 impl<S, V, L> FeldmanVerifierSet<S, V> for GenericArrayFeldmanVerifierSet<S, V, L>
 where
     S: Share,
@@ -596,15 +1488,15 @@ impl<S: Share, G: ShareVerifier<S>> FeldmanVerifierSet<S, G> for Vec<G> {
     }
 
     fn generator(&self) -> G {
-        self[0]
+        self.first().copied().unwrap_or_default()
     }
 
     fn verifiers(&self) -> &[G] {
-        &self[1..]
+        self.get(1..).unwrap_or_default()
     }
 
     fn verifiers_mut(&mut self) -> &mut [G] {
-        self[1..].as_mut()
+        self.get_mut(1..).unwrap_or_default()
     }
 }
 
@@ -706,6 +1598,18 @@ where
     }
 }
 
+#[cfg(all(feature = "arbitrary", any(feature = "alloc", feature = "std")))]
+/// Always contains the generator and at least one verifier
+impl<'a, S, V> arbitrary::Arbitrary<'a> for VecFeldmanVerifierSet<S, V>
+where
+    S: Share,
+    V: ShareVerifier<S> + arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from(Vec::<V>::arbitrary(u)?))
+    }
+}
+
 #[cfg(any(feature = "alloc", feature = "std"))]
 impl<S, V> FeldmanVerifierSet<S, V> for VecFeldmanVerifierSet<S, V>
 where
@@ -734,6 +1638,76 @@ where
     }
 }
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Builds a feldman verifier set one commitment at a time
+/// for when commitments arrive incrementally like in DKG rounds.
+/// ```
+/// #[cfg(any(feature = "alloc", feature = "std"))]
+/// {
+///     use vsss_rs::{DefaultShare, FeldmanVerifierSetBuilder, IdentifierPrimeField, ShareElement, ValueGroup};
+///     use elliptic_curve::Group;
+///     type K256Share = DefaultShare<IdentifierPrimeField<k256::Scalar>, IdentifierPrimeField<k256::Scalar>>;
+///     type K256Verifier = ValueGroup<k256::ProjectivePoint>;
+///
+///     let mut builder = FeldmanVerifierSetBuilder::<K256Share, K256Verifier>::new(2);
+///     builder.push_commitment(ValueGroup(k256::ProjectivePoint::generator()));
+///     builder.push_commitment(ValueGroup(k256::ProjectivePoint::generator().double()));
+///     let set: Vec<K256Verifier> = builder.finish(K256Verifier::one()).unwrap();
+///     assert_eq!(set.len(), 3);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FeldmanVerifierSetBuilder<S, V>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    threshold: usize,
+    commitments: Vec<V>,
+    _marker: PhantomData<S>,
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<S, V> FeldmanVerifierSetBuilder<S, V>
+where
+    S: Share,
+    V: ShareVerifier<S>,
+{
+    /// Create a new builder expecting `threshold` commitments
+    pub fn new(threshold: usize) -> Self {
+        Self {
+            threshold,
+            commitments: Vec::with_capacity(threshold),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Add the next polynomial coefficient commitment
+    pub fn push_commitment(&mut self, commitment: V) -> &mut Self {
+        self.commitments.push(commitment);
+        self
+    }
+
+    /// The commitments received so far
+    pub fn commitments(&self) -> &[V] {
+        &self.commitments
+    }
+
+    /// Create the verifier set with the generator used for the commitments
+    pub fn finish<B: FeldmanVerifierSet<S, V>>(self, generator: V) -> VsssResult<B> {
+        if self.commitments.len() != self.threshold {
+            return Err(Error::InvalidCommitmentCount {
+                expected: self.threshold,
+                actual: self.commitments.len(),
+            });
+        }
+        if generator.is_zero().into() {
+            return Err(Error::InvalidGenerator("Generator is identity"));
+        }
+        B::feldman_set_with_generator_and_verifiers(generator, &self.commitments)
+    }
+}
+
 impl<S: Share, G: ShareVerifier<S>, const L: usize> PedersenVerifierSet<S, G> for [G; L] {
     fn empty_pedersen_set_with_capacity(
         _size_hint: usize,
@@ -865,6 +1839,17 @@ where
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, S, V, const L: usize> arbitrary::Arbitrary<'a> for ArrayPedersenVerifierSet<S, V, L>
+where
+    S: Share,
+    V: ShareVerifier<S> + arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from(<[V; L]>::arbitrary(u)?))
+    }
+}
+
 impl<S, V, const L: usize> PedersenVerifierSet<S, V> for ArrayPedersenVerifierSet<S, V, L>
 where
     S: Share,
@@ -933,6 +1918,36 @@ impl<S: Share, G: ShareVerifier<S>, L: ArrayLength> PedersenVerifierSet<S, G>
     }
 }
 
+#[cfg(feature = "hybrid-array")]
+impl<S: Share, G: ShareVerifier<S>, L: ArraySize> PedersenVerifierSet<S, G> for Array<G, L> {
+    fn empty_pedersen_set_with_capacity(
+        _size_hint: usize,
+        secret_generator: G,
+        blinder_generator: G,
+    ) -> Self {
+        let mut t = Self::default();
+        t[0] = secret_generator;
+        t[1] = blinder_generator;
+        t
+    }
+
+    fn secret_generator(&self) -> G {
+        self[0]
+    }
+
+    fn blinder_generator(&self) -> G {
+        self[1]
+    }
+
+    fn blind_verifiers(&self) -> &[G] {
+        &self[2..]
+    }
+
+    fn blind_verifiers_mut(&mut self) -> &mut [G] {
+        self[2..].as_mut()
+    }
+}
+
 /// A wrapper around a generic array of verifiers
 /// Allows for convenient type aliasing
 /// ```
@@ -1043,6 +2058,22 @@ where
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, S, V, L> arbitrary::Arbitrary<'a> for GenericArrayPedersenVerifierSet<S, V, L>
+where
+    S: Share,
+    V: ShareVerifier<S> + arbitrary::Arbitrary<'a>,
+    L: ArrayLength,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut inner = GenericArray::<V, L>::default();
+        for v in inner.iter_mut() {
+            *v = V::arbitrary(u)?;
+        }
+        Ok(Self::from(inner))
+    }
+}
+
 impl<S, V, L> PedersenVerifierSet<S, V> for GenericArrayPedersenVerifierSet<S, V, L>
 where
     S: Share,
@@ -1095,19 +2126,19 @@ impl<S: Share, V: ShareVerifier<S>> PedersenVerifierSet<S, V> for Vec<V> {
     }
 
     fn secret_generator(&self) -> V {
-        self[0]
+        self.first().copied().unwrap_or_default()
     }
 
     fn blinder_generator(&self) -> V {
-        self[1]
+        self.get(1).copied().unwrap_or_default()
     }
 
     fn blind_verifiers(&self) -> &[V] {
-        &self[2..]
+        self.get(2..).unwrap_or_default()
     }
 
     fn blind_verifiers_mut(&mut self) -> &mut [V] {
-        self[2..].as_mut()
+        self.get_mut(2..).unwrap_or_default()
     }
 }
 
@@ -1209,6 +2240,18 @@ where
     }
 }
 
+#[cfg(all(feature = "arbitrary", any(feature = "alloc", feature = "std")))]
+/// Always contains the generators and at least one verifier
+impl<'a, S, V> arbitrary::Arbitrary<'a> for VecPedersenVerifierSet<S, V>
+where
+    S: Share,
+    V: ShareVerifier<S> + arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from(Vec::<V>::arbitrary(u)?))
+    }
+}
+
 #[cfg(any(feature = "alloc", feature = "std"))]
 impl<S, V> PedersenVerifierSet<S, V> for VecPedersenVerifierSet<S, V>
 where
@@ -1256,7 +2299,7 @@ fn test_feldman_with_generator_and_verifiers() {
 
     let set = <[ShareVerifierK256; 8] as FeldmanVerifierSet<K256Share, ShareVerifierK256>>::feldman_set_with_generator_and_verifiers(
         ValueGroup(k256::ProjectivePoint::GENERATOR),
-        &[ValueGroup(k256::ProjectivePoint::IDENTITY); 7]);
+        &[ValueGroup(k256::ProjectivePoint::IDENTITY); 7]).unwrap();
     assert_eq!(
         ValueGroup(k256::ProjectivePoint::GENERATOR),
         <[ShareVerifierK256; 8] as FeldmanVerifierSet<K256Share, ShareVerifierK256>>::generator(
@@ -1270,3 +2313,112 @@ fn test_feldman_with_generator_and_verifiers() {
         )
     );
 }
+
+#[test]
+fn test_feldman_with_generator_and_verifiers_wrong_length() {
+    type IdK256 = IdentifierPrimeField<k256::Scalar>;
+    type VK256 = ValuePrimeField<k256::Scalar>;
+    type ShareVerifierK256 = ShareVerifierGroup<k256::ProjectivePoint>;
+    type K256Share = (IdK256, VK256);
+
+    let generator = ValueGroup(k256::ProjectivePoint::GENERATOR);
+    let res = <[ShareVerifierK256; 8] as FeldmanVerifierSet<K256Share, ShareVerifierK256>>::feldman_set_with_generator_and_verifiers(
+        generator,
+        &[ValueGroup(k256::ProjectivePoint::IDENTITY); 9]);
+    assert_eq!(res.unwrap_err(), Error::InvalidSizeRequest);
+    let res = <[ShareVerifierK256; 8] as FeldmanVerifierSet<K256Share, ShareVerifierK256>>::feldman_set_with_generator_and_verifiers(
+        generator,
+        &[ValueGroup(k256::ProjectivePoint::IDENTITY); 3]);
+    assert_eq!(res.unwrap_err(), Error::InvalidSizeRequest);
+}
+
+#[test]
+fn test_pedersen_with_generators_and_verifiers_wrong_length() {
+    use generic_array::typenum::U8;
+
+    type IdK256 = IdentifierPrimeField<k256::Scalar>;
+    type VK256 = ValuePrimeField<k256::Scalar>;
+    type ShareVerifierK256 = ShareVerifierGroup<k256::ProjectivePoint>;
+    type K256Share = (IdK256, VK256);
+
+    let generator = ValueGroup(k256::ProjectivePoint::GENERATOR);
+    let blinder_generator = ValueGroup(k256::ProjectivePoint::GENERATOR.double());
+    let res = <[ShareVerifierK256; 8] as PedersenVerifierSet<K256Share, ShareVerifierK256>>::pedersen_set_with_generators_and_verifiers(
+        generator,
+        blinder_generator,
+        &[ValueGroup(k256::ProjectivePoint::IDENTITY); 7]);
+    assert_eq!(res.unwrap_err(), Error::InvalidSizeRequest);
+    let res = ArrayPedersenVerifierSet::<K256Share, ShareVerifierK256, 8>::pedersen_set_with_generators_and_verifiers(
+        generator,
+        blinder_generator,
+        &[ValueGroup(k256::ProjectivePoint::IDENTITY); 5]);
+    assert_eq!(res.unwrap_err(), Error::InvalidSizeRequest);
+    let res = GenericArrayPedersenVerifierSet::<K256Share, ShareVerifierK256, U8>::pedersen_set_with_generators_and_verifiers(
+        generator,
+        blinder_generator,
+        &[ValueGroup(k256::ProjectivePoint::IDENTITY); 9]);
+    assert_eq!(res.unwrap_err(), Error::InvalidSizeRequest);
+
+    let set = ArrayPedersenVerifierSet::<K256Share, ShareVerifierK256, 8>::pedersen_set_with_generators_and_verifiers(
+        generator,
+        blinder_generator,
+        &[ValueGroup(k256::ProjectivePoint::IDENTITY); 6]).unwrap();
+    assert_eq!(set.secret_generator(), generator);
+    assert_eq!(set.blinder_generator(), blinder_generator);
+    assert_eq!(
+        set.blind_verifiers(),
+        &[ValueGroup(k256::ProjectivePoint::IDENTITY); 6]
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn test_feldman_verify_share_empty_verifier_set() {
+    use elliptic_curve::Field;
+
+    type K256Share = (
+        IdentifierPrimeField<k256::Scalar>,
+        IdentifierPrimeField<k256::Scalar>,
+    );
+
+    let mut rng = tests::utils::MockRng::default();
+    let share = K256Share::with_identifier_and_value(
+        IdentifierPrimeField(k256::Scalar::ONE),
+        IdentifierPrimeField(k256::Scalar::random(&mut rng)),
+    );
+    for len in 0..2 {
+        let verifiers = vec![ValueGroup(k256::ProjectivePoint::GENERATOR); len];
+        assert_eq!(
+            FeldmanVerifierSet::<K256Share, _>::verify_share(&verifiers, &share),
+            Err(Error::InvalidGenerator("verifier set is empty"))
+        );
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn test_evaluate_commitments_straus() {
+    use elliptic_curve::Field;
+
+    type BlsShare = (
+        IdentifierPrimeField<bls12_381_plus::Scalar>,
+        IdentifierPrimeField<bls12_381_plus::Scalar>,
+    );
+    type BlsVerifier = ValueGroup<bls12_381_plus::G1Projective>;
+
+    let mut rng = tests::utils::MockRng::default();
+    for threshold in [4, 16, 64] {
+        let commitments = (0..threshold)
+            .map(|_| ValueGroup(bls12_381_plus::G1Projective::random(&mut rng)))
+            .collect::<Vec<BlsVerifier>>();
+        for x in [
+            IdentifierPrimeField(bls12_381_plus::Scalar::ONE),
+            IdentifierPrimeField(bls12_381_plus::Scalar::from(threshold as u64 + 1)),
+            IdentifierPrimeField(bls12_381_plus::Scalar::random(&mut rng)),
+        ] {
+            let naive = evaluate_commitments_naive::<BlsShare, BlsVerifier>(&commitments, &x);
+            let fast = evaluate_commitments_straus::<BlsShare, BlsVerifier>(&commitments, &x);
+            assert_eq!(fast, Some(naive));
+        }
+    }
+}
@@ -5,8 +5,20 @@
 //! Secret splitting for Shamir Secret Sharing Scheme
 //! and combine methods for field and group elements
 use super::*;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::collections::BTreeMap;
+#[cfg(any(feature = "alloc", feature = "std"))]
+use elliptic_curve::PrimeField;
 use generic_array::{ArrayLength, GenericArray};
 use rand_core::{CryptoRng, RngCore};
+#[cfg(any(feature = "alloc", feature = "std"))]
+use sha3::{digest::Update, Shake256};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+#[cfg(all(feature = "zeroize", any(feature = "alloc", feature = "std")))]
+use zeroize::Zeroizing;
 
 /// A Polynomial that can create secret shares
 pub trait Shamir<S>
@@ -30,6 +42,45 @@ where
         Self::split_secret_with_participant_generator(threshold, limit, secret, rng, &[generator])
     }
 
+    #[cfg(feature = "zeroize")]
+    /// Create shares from a secret taken by value.
+    /// The secret is zeroized once the shares have been created
+    /// regardless of whether splitting succeeded.
+    fn split_secret_owned(
+        threshold: usize,
+        limit: usize,
+        mut secret: S::Value,
+        rng: impl RngCore + CryptoRng,
+    ) -> VsssResult<Self::ShareSet>
+    where
+        S::Value: Zeroize,
+    {
+        let res = Self::split_secret(threshold, limit, &secret, rng);
+        secret.zeroize();
+        res
+    }
+
+    /// Create shares from a secret and return them with the polynomial
+    /// whose coefficients the shares were evaluated from.
+    ///
+    /// The polynomial holds the secret as its constant term
+    /// and must be protected like the secret itself.
+    fn split_secret_with_polynomial(
+        threshold: usize,
+        limit: usize,
+        secret: &S::Value,
+        rng: impl RngCore + CryptoRng,
+    ) -> VsssResult<(Self::ShareSet, Self::InnerPolynomial)> {
+        let generator = ParticipantIdGeneratorType::<S::Identifier>::default();
+        Self::split_secret_with_participant_generator_and_polynomial(
+            threshold,
+            limit,
+            secret,
+            rng,
+            &[generator],
+        )
+    }
+
     /// Create shares from a secret and a participant number generator.
     /// `F` is the prime field
     fn split_secret_with_participant_generator(
@@ -39,6 +90,28 @@ where
         rng: impl RngCore + CryptoRng,
         participant_generators: &[ParticipantIdGeneratorType<S::Identifier>],
     ) -> VsssResult<Self::ShareSet> {
+        let (ss, _) = Self::split_secret_with_participant_generator_and_polynomial(
+            threshold,
+            limit,
+            secret,
+            rng,
+            participant_generators,
+        )?;
+        Ok(ss)
+    }
+
+    /// Create shares from a secret and a participant number generator
+    /// and return them with the polynomial they were evaluated from.
+    fn split_secret_with_participant_generator_and_polynomial(
+        threshold: usize,
+        limit: usize,
+        secret: &S::Value,
+        rng: impl RngCore + CryptoRng,
+        participant_generators: &[ParticipantIdGeneratorType<S::Identifier>],
+    ) -> VsssResult<(Self::ShareSet, Self::InnerPolynomial)> {
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!("split_secret", scheme = "shamir", threshold, limit).entered();
         check_params(threshold, limit)?;
         let mut polynomial = Self::InnerPolynomial::create(threshold);
         polynomial.fill(secret, rng, threshold)?;
@@ -48,7 +121,7 @@ where
             limit,
             participant_generators,
         )?;
-        Ok(ss)
+        Ok((ss, polynomial))
     }
 }
 
@@ -93,6 +166,19 @@ pub(crate) fn check_params(threshold: usize, limit: usize) -> VsssResult<()> {
     Ok(())
 }
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub(crate) fn check_identifiers<I: ShareIdentifier>(ids: &[I]) -> VsssResult<()> {
+    for (i, id) in ids.iter().enumerate() {
+        if id.is_zero().into() {
+            return Err(Error::SharingInvalidIdentifier);
+        }
+        if ids[i + 1..].contains(id) {
+            return Err(Error::SharingDuplicateIdentifier);
+        }
+    }
+    Ok(())
+}
+
 impl<S: Share, const L: usize> Shamir<S> for [S; L] {
     type InnerPolynomial = [S; L];
     type ShareSet = [S; L];
@@ -120,6 +206,427 @@ pub fn split_secret<S: Share>(
     StdVsssShamir::split_secret(threshold, limit, secret, rng)
 }
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+const DETERMINISTIC_SPLIT_DST: &[u8] = b"vsss-rs-deterministic-split-v1";
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Create shares from a secret with polynomial coefficients derived from
+/// the secret and `domain` instead of fresh randomness.
+///
+/// The coefficients are read from Shake256 over a fixed domain separation
+/// tag, `threshold` and `limit` as big endian `u64`s, the length of `domain`,
+/// `domain` and the secret bytes, so splitting the same secret with the same
+/// `domain`, `threshold` and `limit` always yields the same shares and
+/// changing any of them yields unrelated coefficients.
+/// Anyone who learns the secret can recompute every share, and shares from
+/// two splits with the same inputs are identical, so use a distinct
+/// `domain` per purpose.
+pub fn split_secret_deterministic<S: Share>(
+    threshold: usize,
+    limit: usize,
+    secret: &S::Value,
+    domain: &[u8],
+) -> VsssResult<Vec<S>> {
+    let mut hasher = Shake256::default();
+    hasher.update(DETERMINISTIC_SPLIT_DST);
+    hasher.update(&(threshold as u64).to_be_bytes());
+    hasher.update(&(limit as u64).to_be_bytes());
+    hasher.update(&(domain.len() as u64).to_be_bytes());
+    hasher.update(domain);
+    #[cfg(feature = "zeroize")]
+    let secret_bytes = Zeroizing::new(secret.to_vec());
+    #[cfg(not(feature = "zeroize"))]
+    let secret_bytes = secret.to_vec();
+    hasher.update(&secret_bytes);
+    StdVsssShamir::split_secret(threshold, limit, secret, XofRng::new(hasher))
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Create shares of a secret group element like a public key.
+///
+/// The polynomial has the secret as its constant term and random
+/// multiples of the group generator as its other coefficients, so each
+/// share value is a group element and the identifiers must be scalars of `G`,
+/// which is required by the [`Share`] bound `ValueGroup<G>: From<&S::Identifier>`.
+/// Combine the shares with [`combine_group_secret`] to interpolate in the group.
+pub fn split_group_secret<S, G>(
+    threshold: usize,
+    limit: usize,
+    secret: &ValueGroup<G>,
+    rng: impl RngCore + CryptoRng,
+) -> VsssResult<Vec<S>>
+where
+    S: Share<Value = ValueGroup<G>>,
+    G: Group + GroupEncoding + Default,
+{
+    split_secret::<S>(threshold, limit, secret, rng)
+}
+
+/// Combine shares into the secret.
+///
+/// This is the low level reconstruction that [`Gf256::combine_array`]
+/// performs for each byte and is the same as [`ReadableShareSet::combine`].
+/// The share type `S` determines both the identifier and value types. Returns
+/// [`Error::SharingMinThreshold`] for fewer than two shares,
+/// [`Error::SharingInvalidIdentifier`] for a zero identifier and
+/// [`Error::SharingDuplicateIdentifier`] for a repeated identifier.
+///
+/// Reconstructing a byte in GF(256)
+/// ```
+/// #[cfg(any(feature = "alloc", feature = "std"))]
+/// {
+///     use vsss_rs::{shamir, DefaultShare, Gf256, IdentifierGf256};
+///     type GfShare = DefaultShare<IdentifierGf256, IdentifierGf256>;
+///
+///     let mut rng = rand_core::OsRng;
+///     let secret = IdentifierGf256(Gf256(42));
+///     let shares = shamir::split_secret::<GfShare>(2, 3, &secret, &mut rng).unwrap();
+///     assert_eq!(shamir::combine_shares(&shares[1..]).unwrap(), secret);
+/// }
+/// ```
+///
+/// Reconstructing a k256 scalar
+/// ```
+/// #[cfg(any(feature = "alloc", feature = "std"))]
+/// {
+///     use vsss_rs::{shamir, DefaultShare, IdentifierPrimeField, ShareElement};
+///     type K256Share = DefaultShare<IdentifierPrimeField<k256::Scalar>, IdentifierPrimeField<k256::Scalar>>;
+///
+///     let mut rng = rand_core::OsRng;
+///     let secret = IdentifierPrimeField::<k256::Scalar>::random(&mut rng);
+///     let shares = shamir::split_secret::<K256Share>(2, 3, &secret, &mut rng).unwrap();
+///     assert_eq!(shamir::combine_shares(&shares[..2]).unwrap(), secret);
+/// }
+/// ```
+pub fn combine_shares<S: Share>(shares: &[S]) -> VsssResult<S::Value> {
+    shares.combine()
+}
+
+/// Combine group valued shares created by [`split_group_secret`]
+/// into the secret group element.
+pub fn combine_group_secret<S, G>(shares: &[S]) -> VsssResult<ValueGroup<G>>
+where
+    S: Share<Value = ValueGroup<G>>,
+    G: Group + GroupEncoding + Default,
+{
+    shares.combine()
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Create shares from a secret keyed by their identifiers.
+pub fn split_secret_map<S: Share>(
+    threshold: usize,
+    limit: usize,
+    secret: &S::Value,
+    rng: impl RngCore + CryptoRng,
+) -> VsssResult<BTreeMap<S::Identifier, S::Value>>
+where
+    S::Identifier: Ord,
+{
+    let shares = split_secret::<S>(threshold, limit, secret, rng)?;
+    Ok(shares
+        .into_iter()
+        .map(|s| (s.identifier().clone(), s.value().clone()))
+        .collect())
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Create shares for each of `secrets` using the same participant identifiers.
+///
+/// The identifiers are generated once and every secret is split with
+/// its own random polynomial, so the share at index `i` of each returned
+/// set belongs to the same participant.
+/// Returns [`Error::InvalidSizeRequest`] if `secrets` is empty.
+pub fn split_secrets<S: Share>(
+    threshold: usize,
+    limit: usize,
+    secrets: &[S::Value],
+    mut rng: impl RngCore + CryptoRng,
+) -> VsssResult<Vec<Vec<S>>> {
+    check_params(threshold, limit)?;
+    if secrets.is_empty() {
+        return Err(Error::InvalidSizeRequest);
+    }
+    let generator = ParticipantIdGeneratorType::<S::Identifier>::default();
+    let ids = ParticipantIdGeneratorCollection::from(&[generator][..])
+        .iter()
+        .take(limit)
+        .collect::<Vec<_>>();
+    let generator = [ParticipantIdGeneratorType::list(&ids)];
+    secrets
+        .iter()
+        .map(|secret| {
+            split_secret_with_participant_generator(threshold, limit, secret, &mut rng, &generator)
+        })
+        .collect()
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Create shares for participants with different weights.
+///
+/// A participant with weight `w` receives a bundle of `w` shares with
+/// consecutive identifiers so any set of participants whose weights sum
+/// to at least `threshold` can reconstruct the secret with [`combine_weighted`].
+/// Returns [`Error::SharingMinThreshold`] if the total weight is less than
+/// `threshold` and [`Error::InvalidSizeRequest`] if any weight is zero.
+pub fn split_secret_weighted<S: Share>(
+    threshold: usize,
+    weights: &[usize],
+    secret: &S::Value,
+    rng: impl RngCore + CryptoRng,
+) -> VsssResult<Vec<Vec<S>>> {
+    if weights.contains(&0) {
+        return Err(Error::InvalidSizeRequest);
+    }
+    let total = weights
+        .iter()
+        .try_fold(0usize, |acc, w| acc.checked_add(*w))
+        .ok_or(Error::InvalidSizeRequest)?;
+    if total < threshold {
+        return Err(Error::SharingMinThreshold);
+    }
+    let mut shares = split_secret::<S>(threshold, total, secret, rng)?.into_iter();
+    Ok(weights
+        .iter()
+        .map(|w| shares.by_ref().take(*w).collect())
+        .collect())
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Combine the share bundles from [`split_secret_weighted`] into the secret.
+pub fn combine_weighted<S: Share, B: AsRef<[S]>>(bundles: &[B]) -> VsssResult<S::Value> {
+    let shares = bundles
+        .iter()
+        .flat_map(|b| b.as_ref().iter().cloned())
+        .collect::<Vec<_>>();
+    shares.combine()
+}
+
+/// Which polynomial coefficient holds the secret
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SecretPosition {
+    /// The secret is the constant term, the standard placement
+    #[default]
+    Constant,
+    /// The secret is the highest degree coefficient as used by some older tools
+    Leading,
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Create shares from a secret placed at `position` in the polynomial.
+pub fn split_secret_with_position<S: Share>(
+    threshold: usize,
+    limit: usize,
+    secret: &S::Value,
+    position: SecretPosition,
+    mut rng: impl RngCore + CryptoRng,
+) -> VsssResult<Vec<S>> {
+    if position == SecretPosition::Constant {
+        return split_secret::<S>(threshold, limit, secret, rng);
+    }
+    check_params(threshold, limit)?;
+    let generator = ParticipantIdGeneratorType::<S::Identifier>::default();
+    let ids = ParticipantIdGeneratorCollection::from(&[generator][..])
+        .iter()
+        .take(limit)
+        .collect::<Vec<_>>();
+    if ids.len() < limit {
+        return Err(Error::NotEnoughShareIdentifiers);
+    }
+
+    // The lower degree terms are a random polynomial of degree threshold - 2
+    let mut lower = <Vec<S> as Polynomial<S>>::create(threshold - 1);
+    let intercept = S::Value::random(&mut rng);
+    lower.fill(&intercept, &mut rng, threshold - 1)?;
+
+    let mut shares = Vec::with_capacity(limit);
+    for x in ids {
+        let mut x_pow = S::Identifier::one();
+        for _ in 1..threshold {
+            *x_pow.as_mut() *= x.as_ref();
+        }
+        let mut value = lower.evaluate(&x, threshold - 1);
+        let leading = secret.clone() * &x_pow;
+        *value.as_mut() += leading.as_ref();
+        shares.push(S::with_identifier_and_value(x, value));
+    }
+    Ok(shares)
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Combine shares created with the secret at `position`.
+///
+/// [`SecretPosition::Leading`] requires exactly `threshold` shares since
+/// the secret is the top coefficient of the polynomial through them,
+/// otherwise [`Error::InvalidSizeRequest`] is returned.
+/// [`SecretPosition::Constant`] requires at least `threshold` shares.
+pub fn combine_with_position<S: Share>(
+    shares: &[S],
+    threshold: usize,
+    position: SecretPosition,
+) -> VsssResult<S::Value> {
+    if threshold < 2 {
+        return Err(Error::SharingMinThreshold);
+    }
+    match position {
+        SecretPosition::Constant if shares.len() < threshold => {
+            Err(Error::NotEnoughShareIdentifiers)
+        }
+        SecretPosition::Constant => shares.combine(),
+        SecretPosition::Leading if shares.len() != threshold => Err(Error::InvalidSizeRequest),
+        SecretPosition::Leading => combine_polynomial(shares)?
+            .pop()
+            .ok_or(Error::SharingMinThreshold),
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Reconstruct every coefficient of the polynomial through `shares`,
+/// lowest degree first.
+///
+/// The result has one coefficient per share so with more than `threshold`
+/// shares the extra high degree coefficients are zero.
+pub fn combine_polynomial<S: Share>(shares: &[S]) -> VsssResult<Vec<S::Value>> {
+    if shares.len() < 2 {
+        return Err(Error::SharingMinThreshold);
+    }
+    let ids = shares
+        .iter()
+        .map(|s| s.identifier().clone())
+        .collect::<Vec<_>>();
+    check_identifiers(&ids)?;
+
+    let mut coefficients = vec![S::Value::zero(); shares.len()];
+    for (i, share) in shares.iter().enumerate() {
+        let basis = lagrange_basis_coefficients(ids.len(), |j| &ids[j], i)?;
+        for (c, b) in coefficients.iter_mut().zip(basis.iter()) {
+            let term = share.value().clone() * b;
+            *c.as_mut() += term.as_ref();
+        }
+    }
+    Ok(coefficients)
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Count how many distinct polynomials of degree less than `threshold`
+/// the pooled `shares` lie on e.g. to check a pool holds shares from a
+/// single dealing before combining it.
+///
+/// Groups are found greedily: the first ungrouped share is grouped with the
+/// `threshold - 1` other shares whose polynomial passes through the most
+/// ungrouped shares, and all of those are removed. Shares of a dealing are
+/// only recognized as a group when at least `threshold` of them are pooled.
+/// This tries every choice of `threshold - 1` shares so it is meant for
+/// small pools.
+pub fn count_consistent_groups<S: Share>(shares: &[S], threshold: usize) -> VsssResult<usize> {
+    if threshold < 2 {
+        return Err(Error::SharingMinThreshold);
+    }
+    if shares.len() < threshold {
+        return Err(Error::NotEnoughShareIdentifiers);
+    }
+    if let Some(index) = shares
+        .iter()
+        .position(|s| bool::from(s.identifier().is_zero()))
+    {
+        return Err(Error::IdentifierOutOfRange { index });
+    }
+
+    let mut remaining = (0..shares.len()).collect::<Vec<_>>();
+    let mut groups = 0;
+    while !remaining.is_empty() {
+        groups += 1;
+        let first = remaining[0];
+        let others = &remaining[1..];
+        if others.len() < threshold - 1 {
+            // Too few shares left to disagree with any polynomial
+            break;
+        }
+        let mut best = vec![first];
+        let mut choice = (0..threshold - 1).collect::<Vec<_>>();
+        loop {
+            let basis = core::iter::once(first)
+                .chain(choice.iter().map(|&c| others[c]))
+                .map(|i| shares[i].clone())
+                .collect::<Vec<_>>();
+            let ids = basis
+                .iter()
+                .map(|s| s.identifier().clone())
+                .collect::<Vec<_>>();
+            if check_identifiers(&ids).is_ok() {
+                let mut support = Vec::with_capacity(remaining.len());
+                for &i in &remaining {
+                    if basis.combine_to_point(shares[i].identifier())? == *shares[i].value() {
+                        support.push(i);
+                    }
+                }
+                if support.len() > best.len() {
+                    best = support;
+                }
+            }
+            // Advance to the next choice of others in lexicographic order
+            let Some(k) = (0..choice.len())
+                .rev()
+                .find(|&k| choice[k] < others.len() - choice.len() + k)
+            else {
+                break;
+            };
+            choice[k] += 1;
+            for j in k + 1..choice.len() {
+                choice[j] = choice[j - 1] + 1;
+            }
+        }
+        remaining.retain(|i| !best.contains(i));
+    }
+    Ok(groups)
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Create shares from a secret and return them with the polynomial
+/// whose coefficients the shares were evaluated from.
+///
+/// The constant term holds the secret in its value and the other
+/// coefficients are held in the identifiers. Use
+/// [`split_secret_with_zeroizing_polynomial`] to have the polynomial
+/// zeroized when it is dropped.
+pub fn split_secret_with_polynomial<S: Share>(
+    threshold: usize,
+    limit: usize,
+    secret: &S::Value,
+    rng: impl RngCore + CryptoRng,
+) -> VsssResult<(Vec<S>, Vec<S>)> {
+    StdVsssShamir::split_secret_with_polynomial(threshold, limit, secret, rng)
+}
+
+#[cfg(all(feature = "zeroize", any(feature = "alloc", feature = "std")))]
+/// Create shares from a secret and return them with the polynomial
+/// whose coefficients the shares were evaluated from.
+/// The polynomial is zeroized when it is dropped.
+pub fn split_secret_with_zeroizing_polynomial<S: Share + Zeroize>(
+    threshold: usize,
+    limit: usize,
+    secret: &S::Value,
+    rng: impl RngCore + CryptoRng,
+) -> VsssResult<(Vec<S>, Zeroizing<Vec<S>>)> {
+    let (shares, polynomial) = split_secret_with_polynomial(threshold, limit, secret, rng)?;
+    Ok((shares, Zeroizing::new(polynomial)))
+}
+
+#[cfg(all(feature = "zeroize", any(feature = "alloc", feature = "std")))]
+/// Create shares from a secret taken by value.
+/// The secret is zeroized once the shares have been created.
+pub fn split_secret_owned<S: Share>(
+    threshold: usize,
+    limit: usize,
+    secret: S::Value,
+    rng: impl RngCore + CryptoRng,
+) -> VsssResult<Vec<S>>
+where
+    S::Value: Zeroize,
+{
+    StdVsssShamir::split_secret_owned(threshold, limit, secret, rng)
+}
+
 #[cfg(any(feature = "alloc", feature = "std"))]
 /// Create shares from a secret and a participant number generator.
 pub fn split_secret_with_participant_generator<S: Share>(
@@ -138,6 +645,80 @@ pub fn split_secret_with_participant_generator<S: Share>(
     )
 }
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Create shares from a secret whose identifiers are serialized with
+/// the fewest bytes that fit `limit` to minimize the size of each share.
+///
+/// The identifiers are `1..=limit` encoded as big endian unsigned integers
+/// of 1, 2, 4 or 8 bytes, e.g. a single byte when `limit` is at most 255.
+/// Values use the canonical field encoding which is already minimal.
+/// Combine the shares with [`combine_widened`].
+pub fn split_secret_compact<F: PrimeField>(
+    threshold: usize,
+    limit: usize,
+    secret: &IdentifierPrimeField<F>,
+    rng: impl RngCore + CryptoRng,
+) -> VsssResult<Vec<(Vec<u8>, IdentifierPrimeField<F>)>> {
+    let width = [1usize, 2, 4, 8]
+        .into_iter()
+        .find(|w| *w == 8 || (limit as u64) < 1u64 << (8 * w))
+        .unwrap_or(8);
+    let shares = split_secret::<(IdentifierPrimeField<F>, IdentifierPrimeField<F>)>(
+        threshold, limit, secret, rng,
+    )?;
+    Ok(shares
+        .into_iter()
+        .enumerate()
+        .map(|(i, (_, value))| {
+            let id = (i as u64 + 1).to_be_bytes();
+            (id[8 - width..].to_vec(), value)
+        })
+        .collect())
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Combine shares whose identifiers are big endian unsigned integers of any width.
+///
+/// Identifiers serialized from different integer widths, e.g. a `u16`
+/// and a `u32` identifier, are normalized to the same field element so
+/// shares from mixed deployments can be combined together.
+/// Returns [`Error::SharingInvalidIdentifier`] if an identifier is empty.
+pub fn combine_widened<F: PrimeField>(
+    shares: &[(&[u8], IdentifierPrimeField<F>)],
+) -> VsssResult<IdentifierPrimeField<F>> {
+    let radix = F::from(256u64);
+    let shares = shares
+        .iter()
+        .map(|(id, value)| {
+            if id.is_empty() {
+                return Err(Error::SharingInvalidIdentifier);
+            }
+            let id = id
+                .iter()
+                .fold(F::ZERO, |acc, b| acc * radix + F::from(u64::from(*b)));
+            Ok((IdentifierPrimeField(id), *value))
+        })
+        .collect::<VsssResult<Vec<_>>>()?;
+    shares.combine()
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// Combine shares read from a fallible source like a network connection.
+///
+/// Stops at the first item that is an error and returns it as
+/// [`CombineOrSourceError::Source`], otherwise combines all shares.
+pub fn combine_try_from_iter<S, E, I>(iter: I) -> Result<S::Value, CombineOrSourceError<E>>
+where
+    S: Share,
+    I: IntoIterator<Item = Result<S, E>>,
+{
+    let shares = iter
+        .into_iter()
+        .collect::<Result<Vec<S>, E>>()
+        .map_err(CombineOrSourceError::Source)?;
+    Ok(shares.combine()?)
+}
+
 #[cfg(any(feature = "alloc", feature = "std"))]
 struct StdVsssShamir<S: Share> {
     _marker: core::marker::PhantomData<S>,
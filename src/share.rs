@@ -1,11 +1,17 @@
 use super::*;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::String;
+#[cfg(any(feature = "alloc", feature = "std"))]
+use core::any::TypeId;
 use core::{
     cmp::Ordering,
-    fmt::Debug,
+    fmt::{self, Debug, Formatter},
     hash::{Hash, Hasher},
-    ops::Mul,
+    ops::{Add, Mul, Sub},
 };
 use elliptic_curve::PrimeField;
+#[cfg(feature = "std")]
+use std::string::String;
 #[cfg(feature = "zeroize")]
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
@@ -29,6 +35,95 @@ pub trait Share: Sized + Debug + Eq + PartialEq + Clone + Default {
     fn value(&self) -> &Self::Value;
     /// The mutable share value
     fn value_mut(&mut self) -> &mut Self::Value;
+
+    /// The identifier for this share or [`Error::InvalidShare`]
+    /// if it is zero like in a default constructed share.
+    fn try_identifier(&self) -> VsssResult<&Self::Identifier> {
+        let identifier = self.identifier();
+        if identifier.is_zero().into() {
+            return Err(Error::InvalidShare);
+        }
+        Ok(identifier)
+    }
+
+    /// The share value or [`Error::InvalidShare`] if it is zero
+    /// which verifiers reject as it commits to nothing.
+    fn try_value(&self) -> VsssResult<&Self::Value> {
+        let value = self.value();
+        if value.is_zero().into() {
+            return Err(Error::InvalidShare);
+        }
+        Ok(value)
+    }
+
+    /// Parse a share from untrusted identifier and value bytes.
+    ///
+    /// Both parts are decoded with [`ShareElement::from_slice`] so a
+    /// value that isn't a canonical element, e.g. a scalar greater than
+    /// the field modulus, returns [`Error::InvalidShareElement`] instead
+    /// of being reduced or panicking.
+    fn from_bytes(identifier_bytes: &[u8], value_bytes: &[u8]) -> VsssResult<Self> {
+        let identifier = Self::Identifier::from_slice(identifier_bytes)?;
+        let value = Self::Value::from_slice(value_bytes)?;
+        Ok(Self::with_identifier_and_value(identifier, value))
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    /// Encode this share as a `vsss1:` prefixed base64url string.
+    ///
+    /// The encoded bytes are a version byte and the identifier and value
+    /// lengths followed by the identifier and value bytes.
+    fn to_encoded_string(&self) -> String {
+        let bytes = encoding::frame(&self.identifier().to_vec(), &self.value().to_vec());
+        encoding::encode(encoding::BASE64_PREFIX, &bytes)
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    /// Encode this share as a `vssh1:` prefixed hex string
+    /// with the same framing as [`Share::to_encoded_string`].
+    fn to_encoded_hex_string(&self) -> String {
+        let bytes = encoding::frame(&self.identifier().to_vec(), &self.value().to_vec());
+        encoding::encode(encoding::HEX_PREFIX, &bytes)
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    /// Decode a share from a `vsss1:` base64url or `vssh1:` hex string.
+    ///
+    /// Returns [`Error::InvalidShareConversion`] for an unknown prefix, invalid
+    /// characters or truncated input and [`Error::InvalidShareElement`] if
+    /// the identifier or value bytes are not valid elements.
+    fn from_encoded_string(s: &str) -> VsssResult<Self> {
+        let bytes = encoding::decode(s)?;
+        let (identifier, value) = encoding::unframe(&bytes)?;
+        Self::from_bytes(identifier, value)
+    }
+
+    /// Verify this share against a feldman verifier set.
+    /// Equivalent to `verifier_set.verify_share(self)`.
+    /// ```
+    /// #[cfg(any(feature = "alloc", feature = "std"))]
+    /// {
+    ///     use vsss_rs::{feldman, DefaultShare, IdentifierPrimeField, Share, ValueGroup};
+    ///     use elliptic_curve::Field;
+    ///     type K256Share = DefaultShare<IdentifierPrimeField<k256::Scalar>, IdentifierPrimeField<k256::Scalar>>;
+    ///     type K256Verifier = ValueGroup<k256::ProjectivePoint>;
+    ///
+    ///     let mut rng = rand_core::OsRng;
+    ///     let secret = IdentifierPrimeField(k256::Scalar::random(&mut rng));
+    ///     let (shares, feldman_set) =
+    ///         feldman::split_secret::<K256Share, K256Verifier>(2, 3, &secret, None, &mut rng).unwrap();
+    ///     for share in &shares {
+    ///         assert!(share.verify_against(&feldman_set).is_ok());
+    ///     }
+    /// }
+    /// ```
+    fn verify_against<V, VS>(&self, verifier_set: &VS) -> VsssResult<()>
+    where
+        V: ShareVerifier<Self>,
+        VS: FeldmanVerifierSet<Self, V>,
+    {
+        verifier_set.verify_share(self)
+    }
 }
 
 impl<I, V> Share for (I, V)
@@ -61,7 +156,10 @@ where
 }
 
 /// A default share implementation providing named fields for the identifier and value.
-#[derive(Debug, Clone, Eq, PartialEq, Default)]
+///
+/// The [`Debug`] output always shows the identifier bytes but defers
+/// to the value's [`Debug`] implementation which may redact secret data.
+#[derive(Clone, Eq, PartialEq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DefaultShare<I, V>
 where
@@ -84,6 +182,37 @@ where
     pub value: V,
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, I, V> arbitrary::Arbitrary<'a> for DefaultShare<I, V>
+where
+    I: ShareIdentifier + arbitrary::Arbitrary<'a>,
+    V: ShareElement
+        + for<'b> From<&'b I>
+        + for<'b> Mul<&'b I, Output = V>
+        + arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            identifier: I::arbitrary(u)?,
+            value: V::arbitrary(u)?,
+        })
+    }
+}
+
+impl<I, V> Debug for DefaultShare<I, V>
+where
+    I: ShareIdentifier,
+    V: ShareElement + for<'a> From<&'a I> + for<'a> Mul<&'a I, Output = V>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "DefaultShare {{ identifier: ")?;
+        for b in self.identifier.serialize().as_ref() {
+            write!(f, "{:02x}", b)?;
+        }
+        write!(f, ", value: {:?} }}", self.value)
+    }
+}
+
 impl<I, V> Copy for DefaultShare<I, V>
 where
     I: ShareIdentifier + Copy,
@@ -203,3 +332,296 @@ where
         &mut self.value
     }
 }
+
+/// Adds the values of two shares of the same participant e.g. to
+/// combine their shares of two secrets into a share of the sum.
+///
+/// # Panics
+///
+/// Panics if the identifiers differ, in both debug and release builds,
+/// since the sum of values at different identifiers is not a share.
+/// Use [`DefaultShare::checked_add`] to get an error instead.
+impl<I, V> Add<&Self> for DefaultShare<I, V>
+where
+    I: ShareIdentifier,
+    V: ShareElement + for<'a> From<&'a I> + for<'a> Mul<&'a I, Output = V>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: &Self) -> Self {
+        self.checked_add(rhs)
+            .expect("cannot add shares with different identifiers")
+    }
+}
+
+impl<I, V> Add for DefaultShare<I, V>
+where
+    I: ShareIdentifier,
+    V: ShareElement + for<'a> From<&'a I> + for<'a> Mul<&'a I, Output = V>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self + &rhs
+    }
+}
+
+/// Subtracts the values of two shares of the same participant.
+///
+/// # Panics
+///
+/// Panics if the identifiers differ, in both debug and release builds.
+/// Use [`DefaultShare::checked_sub`] to get an error instead.
+impl<I, V> Sub<&Self> for DefaultShare<I, V>
+where
+    I: ShareIdentifier,
+    V: ShareElement + for<'a> From<&'a I> + for<'a> Mul<&'a I, Output = V>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: &Self) -> Self {
+        self.checked_sub(rhs)
+            .expect("cannot subtract shares with different identifiers")
+    }
+}
+
+impl<I, V> Sub for DefaultShare<I, V>
+where
+    I: ShareIdentifier,
+    V: ShareElement + for<'a> From<&'a I> + for<'a> Mul<&'a I, Output = V>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self - &rhs
+    }
+}
+
+impl<I, V> DefaultShare<I, V>
+where
+    I: ShareIdentifier,
+    V: ShareElement + for<'a> From<&'a I> + for<'a> Mul<&'a I, Output = V>,
+{
+    /// Add the value of `rhs` to this share's value returning
+    /// [`Error::SharingInvalidIdentifier`] if the identifiers differ
+    pub fn checked_add(mut self, rhs: &Self) -> VsssResult<Self> {
+        if self.identifier != rhs.identifier {
+            return Err(Error::SharingInvalidIdentifier);
+        }
+        *self.value.as_mut() += rhs.value.as_ref();
+        Ok(self)
+    }
+
+    /// Subtract the value of `rhs` from this share's value returning
+    /// [`Error::SharingInvalidIdentifier`] if the identifiers differ
+    pub fn checked_sub(mut self, rhs: &Self) -> VsssResult<Self> {
+        if self.identifier != rhs.identifier {
+            return Err(Error::SharingInvalidIdentifier);
+        }
+        *self.value.as_mut() -= rhs.value.as_ref();
+        Ok(self)
+    }
+}
+
+/// The version of the [`DynShare`] interface.
+///
+/// Incremented whenever the byte representation returned by
+/// [`DynShare::identifier_bytes`] or [`DynShare::value_bytes`] changes.
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub const DYN_SHARE_VERSION: u16 = 1;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+/// An object safe share interface.
+///
+/// Allows shares from different fields to be stored together
+/// as `Box<dyn DynShare>`. Every [`Share`] implements this trait
+/// and uses its concrete type as the registered field.
+pub trait DynShare {
+    /// The version of the interface this share was created with
+    fn version(&self) -> u16 {
+        DYN_SHARE_VERSION
+    }
+    /// The field this share belongs to.
+    /// Only shares with the same field can be combined.
+    fn field(&self) -> TypeId;
+    /// The serialized share identifier
+    fn identifier_bytes(&self) -> Vec<u8>;
+    /// The serialized share value
+    fn value_bytes(&self) -> Vec<u8>;
+    /// Combine `shares` that belong to the same field as this share
+    /// and return the serialized secret.
+    /// Each share's bytes are decoded with [`ShareElement::from_slice`] so
+    /// variable length encodings like [`LabelIdentifier`] are accepted and
+    /// bytes that don't decode return its error.
+    fn combine_dyn(&self, shares: &[Box<dyn DynShare>]) -> VsssResult<Vec<u8>>;
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<S: Share + 'static> DynShare for S {
+    fn field(&self) -> TypeId {
+        TypeId::of::<S>()
+    }
+
+    fn identifier_bytes(&self) -> Vec<u8> {
+        self.identifier().to_vec()
+    }
+
+    fn value_bytes(&self) -> Vec<u8> {
+        self.value().to_vec()
+    }
+
+    fn combine_dyn(&self, shares: &[Box<dyn DynShare>]) -> VsssResult<Vec<u8>> {
+        let mut inner_shares = Vec::with_capacity(shares.len());
+        for share in shares {
+            if share.field() != self.field() || share.version() != self.version() {
+                return Err(Error::InvalidShare);
+            }
+            let identifier = S::Identifier::from_slice(&share.identifier_bytes())?;
+            let value = S::Value::from_slice(&share.value_bytes())?;
+            inner_shares.push(S::with_identifier_and_value(identifier, value));
+        }
+        inner_shares.combine().map(|secret| secret.to_vec())
+    }
+}
+
+#[cfg(all(test, any(feature = "alloc", feature = "std")))]
+mod tests {
+    use super::*;
+    use elliptic_curve::Field;
+    use rand_core::SeedableRng;
+
+    type K256Share =
+        DefaultShare<IdentifierPrimeField<k256::Scalar>, ValuePrimeField<k256::Scalar>>;
+    type P256Share =
+        DefaultShare<IdentifierPrimeField<p256::Scalar>, ValuePrimeField<p256::Scalar>>;
+
+    #[test]
+    fn dyn_share_combine() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([3u8; 32]);
+        let k256_secret = IdentifierPrimeField(k256::Scalar::random(&mut rng));
+        let p256_secret = IdentifierPrimeField(p256::Scalar::random(&mut rng));
+
+        let k256_shares = shamir::split_secret::<K256Share>(2, 3, &k256_secret, &mut rng).unwrap();
+        let p256_shares = shamir::split_secret::<P256Share>(2, 3, &p256_secret, &mut rng).unwrap();
+
+        let mut store: Vec<Box<dyn DynShare>> = Vec::new();
+        for (k, p) in k256_shares.into_iter().zip(p256_shares) {
+            store.push(Box::new(k));
+            store.push(Box::new(p));
+        }
+        assert_eq!(store.len(), 6);
+
+        let (k256_store, p256_store): (Vec<_>, Vec<_>) = store
+            .into_iter()
+            .partition(|s| s.field() == TypeId::of::<K256Share>());
+        assert_eq!(k256_store.len(), 3);
+        assert_eq!(p256_store.len(), 3);
+
+        let secret = k256_store[0].combine_dyn(&k256_store[..2]).unwrap();
+        assert_eq!(secret, k256_secret.to_vec());
+        let secret = p256_store[0].combine_dyn(&p256_store[1..]).unwrap();
+        assert_eq!(secret, p256_secret.to_vec());
+
+        // Shares from different fields cannot be combined
+        let mixed = vec![
+            k256_store.into_iter().next().unwrap(),
+            p256_store.into_iter().next().unwrap(),
+        ];
+        assert_eq!(mixed[0].combine_dyn(&mixed), Err(Error::InvalidShare));
+    }
+
+    struct RawShare {
+        field: TypeId,
+        identifier: Vec<u8>,
+        value: Vec<u8>,
+    }
+
+    impl DynShare for RawShare {
+        fn field(&self) -> TypeId {
+            self.field
+        }
+
+        fn identifier_bytes(&self) -> Vec<u8> {
+            self.identifier.clone()
+        }
+
+        fn value_bytes(&self) -> Vec<u8> {
+            self.value.clone()
+        }
+
+        fn combine_dyn(&self, _shares: &[Box<dyn DynShare>]) -> VsssResult<Vec<u8>> {
+            Err(Error::NotImplemented)
+        }
+    }
+
+    #[test]
+    fn dyn_share_combine_mixed_lengths() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([5u8; 32]);
+        let secret = IdentifierPrimeField(k256::Scalar::random(&mut rng));
+        let shares = shamir::split_secret::<K256Share>(2, 3, &secret, &mut rng).unwrap();
+
+        let mut store: Vec<Box<dyn DynShare>> = vec![
+            Box::new(shares[0]),
+            Box::new(RawShare {
+                field: TypeId::of::<K256Share>(),
+                identifier: shares[1].identifier_bytes(),
+                value: vec![1u8; 48],
+            }),
+        ];
+        assert_eq!(store[0].value_bytes().len(), 32);
+        assert_eq!(
+            store[0].combine_dyn(&store),
+            Err(Error::InvalidShareElement)
+        );
+
+        store[1] = Box::new(shares[1]);
+        assert_eq!(store[0].combine_dyn(&store).unwrap(), secret.to_vec());
+    }
+
+    #[test]
+    fn dyn_share_combine_variable_length_identifiers() {
+        type LabelShare = (
+            LabelIdentifier<k256::Scalar>,
+            IdentifierPrimeField<k256::Scalar>,
+        );
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([6u8; 32]);
+        let secret = IdentifierPrimeField(k256::Scalar::random(&mut rng));
+        let labels = ["a", "bob", "carol@example.com"].map(LabelIdentifier::new);
+        let shares = shamir::split_secret_with_participant_generator::<LabelShare>(
+            2,
+            3,
+            &secret,
+            &mut rng,
+            &[ParticipantIdGeneratorType::list(&labels)],
+        )
+        .unwrap();
+
+        let mut store: Vec<Box<dyn DynShare>> = Vec::new();
+        for share in shares {
+            store.push(Box::new(share));
+        }
+        assert_ne!(
+            store[0].identifier_bytes().len(),
+            store[1].identifier_bytes().len()
+        );
+        assert_eq!(store[0].combine_dyn(&store[..2]).unwrap(), secret.to_vec());
+    }
+
+    #[cfg(not(feature = "debug-secrets"))]
+    #[test]
+    fn debug_redacts_value() {
+        use elliptic_curve::PrimeField;
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([4u8; 32]);
+        let share = K256Share::with_identifier_and_value(
+            IdentifierPrimeField(k256::Scalar::from(7u64)),
+            IdentifierPrimeField(k256::Scalar::random(&mut rng)),
+        );
+        let output = format!("{:?}", share);
+        let value_hex = hex::encode(share.value.0.to_repr());
+        assert!(!output.to_lowercase().contains(&value_hex));
+        assert!(output.contains("IdentifierPrimeField(REDACTED)"));
+        assert!(output.contains(&hex::encode(share.identifier.0.to_repr())));
+    }
+}
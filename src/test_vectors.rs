@@ -0,0 +1,89 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+//! Deterministic test vectors for checking other implementations
+//! of Shamir secret sharing against this one.
+//!
+//! [`generate`] derives every value from a seed as follows so another
+//! implementation can reproduce the output byte for byte.
+//!
+//! 1. Initialize SHAKE256 with the 23 byte tag `vsss-rs-test-vectors-v1`
+//!    followed by the seed length as a u64 big endian and the seed.
+//!    Also absorb the threshold and limit each as a u64 big endian.
+//! 2. Sample field elements in order by reading the length of the field's
+//!    canonical byte encoding from the XOF and accepting it if it is a
+//!    canonical encoding, otherwise reading again.
+//!    The first element is the secret, the next `threshold - 1` elements
+//!    are the polynomial coefficients from degree 1 up. Zero coefficients
+//!    are rejected and sampled again.
+//! 3. Share `i` for `i` in `1..=limit` is the polynomial evaluated at the
+//!    field element `i`, i.e. the integer `i` for prime fields
+//!    and the byte `i` for GF(2^8).
+//!
+//! The secret is returned as its canonical encoding. Each share is the
+//! canonical encoding of its identifier followed by the canonical
+//! encoding of its value.
+use crate::*;
+use elliptic_curve::PrimeField;
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+
+/// Generate a deterministic secret and its shares from `seed`.
+///
+/// See the [module documentation](self) for the derivation and encoding.
+pub fn generate<F: PrimeField>(
+    seed: &[u8],
+    threshold: usize,
+    limit: usize,
+) -> VsssResult<(Vec<u8>, Vec<Vec<u8>>)> {
+    check_params(threshold, limit)?;
+    let mut hasher = Shake256::default();
+    hasher.update(b"vsss-rs-test-vectors-v1");
+    hasher.update(&(seed.len() as u64).to_be_bytes());
+    hasher.update(seed);
+    hasher.update(&(threshold as u64).to_be_bytes());
+    hasher.update(&(limit as u64).to_be_bytes());
+    let mut reader = hasher.finalize_xof();
+
+    let secret = sample::<F>(&mut reader);
+    let mut coefficients = Vec::with_capacity(threshold);
+    coefficients.push(secret);
+    while coefficients.len() < threshold {
+        let c = sample::<F>(&mut reader);
+        if !bool::from(c.is_zero()) {
+            coefficients.push(c);
+        }
+    }
+
+    let mut shares = Vec::with_capacity(limit);
+    let mut ids: Vec<F> = Vec::with_capacity(limit);
+    for i in 1..=limit as u64 {
+        let x = F::from(i);
+        if bool::from(x.is_zero()) || ids.contains(&x) {
+            return Err(Error::NotEnoughShareIdentifiers);
+        }
+        ids.push(x);
+        // Horner's method
+        let y = coefficients
+            .iter()
+            .rev()
+            .fold(F::ZERO, |acc, c| acc * x + c);
+        let mut share = x.to_repr().as_ref().to_vec();
+        share.extend_from_slice(y.to_repr().as_ref());
+        shares.push(share);
+    }
+    Ok((secret.to_repr().as_ref().to_vec(), shares))
+}
+
+fn sample<F: PrimeField>(reader: &mut impl XofReader) -> F {
+    let mut repr = F::Repr::default();
+    loop {
+        reader.read(repr.as_mut());
+        if let Some(f) = Option::<F>::from(F::from_repr(repr)) {
+            return f;
+        }
+    }
+}
@@ -80,6 +80,7 @@ fn simple_std() {
         secret_generator: None,
         blinder_generator: None,
         participant_generators: &[numbering],
+        reuse_feldman_commitments: None,
     };
     let ped_res =
         StdPedersenResult::<TestShare<Scalar>, ValueGroup<G1Projective>>::split_secret_with_blind_verifiers(
@@ -105,6 +106,74 @@ fn simple_std() {
     }
 }
 
+#[cfg(feature = "hybrid-array")]
+#[test]
+fn simple_hybrid_array() {
+    use crate::feldman::HybridArrayFeldmanVsss;
+    use crate::pedersen::{HybridArrayPedersenResult, PedersenOptions};
+    use hybrid_array::typenum;
+
+    const THRESHOLD: usize = 3;
+    const SHARES: usize = 5;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField(Scalar::random(&mut rng));
+
+    let shares = HybridArrayFeldmanVsss::<
+        TestShare<Scalar>,
+        ValueGroup<G1Projective>,
+        typenum::U3,
+        typenum::U5,
+    >::split_secret(THRESHOLD, SHARES, &secret, &mut rng)
+    .unwrap();
+    let secret2 = (&shares[..THRESHOLD]).combine().unwrap();
+    assert_eq!(secret, secret2);
+
+    let (shares, verifiers) =
+        HybridArrayFeldmanVsss::<
+            TestShare<Scalar>,
+            ValueGroup<G1Projective>,
+            typenum::U3,
+            typenum::U5,
+        >::split_secret_with_verifier(THRESHOLD, SHARES, &secret, None, &mut rng)
+        .unwrap();
+    for s in &shares {
+        assert!(verifiers.verify_share(s).is_ok());
+    }
+    let secret2 = (&shares[2..]).combine().unwrap();
+    assert_eq!(secret, secret2);
+
+    let numbering = ParticipantIdGeneratorType::default();
+    let options = PedersenOptions {
+        secret,
+        blinder: None,
+        secret_generator: None,
+        blinder_generator: None,
+        participant_generators: &[numbering],
+        reuse_feldman_commitments: None,
+    };
+    let ped_res = HybridArrayPedersenResult::<
+        TestShare<Scalar>,
+        ValueGroup<G1Projective>,
+        typenum::U3,
+        typenum::U5,
+    >::split_secret_with_blind_verifiers(THRESHOLD, SHARES, &options, &mut rng)
+    .unwrap();
+    for (s, bs) in ped_res
+        .secret_shares()
+        .iter()
+        .zip(ped_res.blinder_shares().iter())
+    {
+        assert!(ped_res.feldman_verifier_set().verify_share(s).is_ok());
+        assert!(ped_res
+            .pedersen_verifier_set()
+            .verify_share_and_blinder(s, bs)
+            .is_ok());
+    }
+    let secret2 = (&ped_res.secret_shares()[..THRESHOLD]).combine().unwrap();
+    assert_eq!(secret, secret2);
+}
+
 #[test]
 fn invalid_tests() {
     split_invalid_args::<TestShare<Scalar>, ValueGroup<G1Projective>>();
@@ -245,3 +314,48 @@ fn point_combine() {
     let sig_g1 = (&sigs_g1[..2]).combine().unwrap();
     assert_eq!(sig_g1.0, G1Projective::GENERATOR * *secret);
 }
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn split_group_secret() {
+    type GroupShare = (IdentifierPrimeField<Scalar>, ValueGroup<G1Projective>);
+
+    let mut rng = MockRng::default();
+    let secret = ValueGroup(G1Projective::GENERATOR * Scalar::random(&mut rng));
+    let shares = shamir::split_group_secret::<GroupShare, _>(3, 5, &secret, &mut rng).unwrap();
+    assert_eq!(shares.len(), 5);
+    assert!(shares.iter().all(|s| s.1 != secret));
+
+    for window in shares.windows(3) {
+        assert_eq!(shamir::combine_group_secret(window).unwrap(), secret);
+    }
+    assert_eq!(shamir::combine_group_secret(&shares).unwrap(), secret);
+    assert_ne!(shamir::combine_group_secret(&shares[..2]).unwrap(), secret);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn combine_projective_matches_naive() {
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField(Scalar::random(&mut rng));
+    let shares = shamir::split_secret::<TestShare<Scalar>>(3, 5, &secret, &mut rng)
+        .unwrap()
+        .into_iter()
+        .map(|(id, value)| (id, ValueGroup(G1Projective::GENERATOR * value.0)))
+        .collect::<Vec<_>>();
+    let expected = G1Projective::GENERATOR * secret.0;
+
+    for window in shares.windows(3) {
+        let naive = window.combine().unwrap();
+        let projective = window.combine_projective().unwrap();
+        assert_eq!(naive, projective);
+        assert_eq!(projective.0, expected);
+        assert_eq!(projective.0.to_affine(), G1Affine::from(expected));
+    }
+    let projective = (&shares[..]).combine_projective().unwrap();
+    assert_eq!(projective.0, expected);
+    assert_eq!(
+        (&shares[..1]).combine_projective().unwrap_err(),
+        Error::SharingMinThreshold
+    );
+}
@@ -58,6 +58,31 @@ fn key_tests() {
     assert_eq!(ske1.to_bytes(), ske2.to_bytes());
 }
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn combine_to_scalar_bytes() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    let sc = WrappedScalar::random(&mut rng);
+    let sk = IdentifierPrimeField(sc);
+    let shares = shamir::split_secret::<TestShare<WrappedScalar>>(3, 5, &sk, &mut rng).unwrap();
+
+    let bytes = curve25519::combine_to_scalar_bytes(&shares[..3]).unwrap();
+    assert_eq!(bytes, sc.0.to_bytes());
+    let bytes = curve25519::combine_to_scalar_bytes(&shares[1..4]).unwrap();
+    assert_eq!(
+        StaticSecret::from(bytes).to_bytes(),
+        StaticSecret::from(sc.0.to_bytes()).to_bytes()
+    );
+    assert_eq!(
+        SigningKey::from_bytes(&bytes).to_bytes(),
+        SigningKey::from_bytes(&sc.0.to_bytes()).to_bytes()
+    );
+    assert!(curve25519::combine_to_scalar_bytes(&shares[..1]).is_err());
+}
+
 #[cfg(all(feature = "serde", any(feature = "alloc", feature = "std")))]
 #[test]
 fn pedersen_verifier_serde_test() {
@@ -109,3 +134,46 @@ fn pedersen_verifier_serde_test() {
         PedersenVerifierSet::<TestShare<WrappedScalar>, ValueGroup<WrappedEdwards>>::secret_generator(&verifier2)
     );
 }
+
+#[cfg(any(feature = "json", feature = "cbor"))]
+#[test]
+fn share_envelope() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<WrappedScalar>::random(&mut rng);
+    let shares = shamir::split_secret::<TestShare<WrappedScalar>>(2, 3, &secret, &mut rng).unwrap();
+    let envelopes = shares
+        .iter()
+        .map(|s| ShareEnvelope::new("ed25519-shamir", s))
+        .collect::<Vec<_>>();
+
+    #[cfg(feature = "json")]
+    {
+        let decoded = envelopes
+            .iter()
+            .map(|e| {
+                let e = ShareEnvelope::from_json(&e.to_json().unwrap()).unwrap();
+                assert_eq!(e.scheme, "ed25519-shamir");
+                e.to_share::<TestShare<WrappedScalar>>().unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(decoded, shares);
+        assert_eq!(decoded.combine().unwrap(), secret);
+    }
+    #[cfg(feature = "cbor")]
+    {
+        let decoded = envelopes
+            .iter()
+            .map(|e| {
+                let bytes = e.to_cbor().unwrap();
+                let e2 = ShareEnvelope::from_cbor(&bytes).unwrap();
+                assert_eq!(&e2, e);
+                assert_eq!(e2.to_cbor().unwrap(), bytes);
+                e2.to_share::<TestShare<WrappedScalar>>().unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(decoded, shares);
+        assert_eq!(decoded.combine().unwrap(), secret);
+    }
+}
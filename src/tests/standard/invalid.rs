@@ -35,6 +35,7 @@ pub fn split_invalid_args<S: Share, V: ShareVerifier<S>>() {
         secret_generator: None,
         blinder_generator: None,
         participant_generators: &participant_generators,
+        reuse_feldman_commitments: None,
     };
     assert!(
         GenericArrayPedersenResult::<S, V, U2, U3>::split_secret_with_blind_verifiers(
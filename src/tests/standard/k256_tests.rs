@@ -48,6 +48,2304 @@ fn key_tests() {
     assert_eq!(sk_dup.to_bytes(), sk.to_bytes());
 }
 
+#[cfg(all(feature = "zeroize", any(feature = "alloc", feature = "std")))]
+#[test]
+fn split_secret_owned() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+
+    let mut rng = MockRng::default();
+    let borrowed = shamir::split_secret::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+    let mut rng = MockRng::default();
+    let owned = shamir::split_secret_owned::<TestShare<Scalar>>(3, 5, secret, &mut rng).unwrap();
+    assert_eq!(borrowed, owned);
+    assert_eq!(owned.combine().unwrap(), secret);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn verify_value_at() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    let (shares, verifiers) =
+        feldman::split_secret::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>(
+            3, 5, &secret, None, &mut rng,
+        )
+        .unwrap();
+
+    let verify = |value, id| {
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::verify_value_at(&verifiers, value, id)
+    };
+    for share in &shares {
+        assert!(verify(share.value(), share.identifier()).is_ok());
+    }
+    // Remapped identifiers don't verify
+    assert!(verify(shares[0].value(), shares[1].identifier()).is_err());
+    assert!(verify(
+        shares[4].value(),
+        &IdentifierPrimeField::from(Scalar::from(42u64))
+    )
+    .is_err());
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn split_secret_by_pubkeys() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::{sec1::ToEncodedPoint, Field};
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    let pubkeys = (0..3)
+        .map(|_| {
+            SecretKey::random(&mut rng)
+                .public_key()
+                .to_encoded_point(true)
+                .as_bytes()
+                .to_vec()
+        })
+        .collect::<Vec<_>>();
+    let pubkey_refs = pubkeys.iter().map(|pk| pk.as_slice()).collect::<Vec<_>>();
+
+    let (shares, verifiers) = feldman::split_secret_by_pubkeys::<
+        TestShare<Scalar>,
+        ValueGroup<ProjectivePoint>,
+    >(2, &secret, &pubkey_refs, &mut rng)
+    .unwrap();
+    assert_eq!(shares.len(), 3);
+    for (share, pk) in shares.iter().zip(&pubkey_refs) {
+        assert_eq!(share.identifier(), &hash_to_identifier(pk));
+        assert!(verifiers.verify_share(share).is_ok());
+    }
+    assert_eq!((&shares[..2]).combine().unwrap(), secret);
+    assert_eq!((&shares[1..]).combine().unwrap(), secret);
+
+    let duplicate = [pubkey_refs[0], pubkey_refs[1], pubkey_refs[0]];
+    let res = feldman::split_secret_by_pubkeys::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>(
+        2, &secret, &duplicate, &mut rng,
+    );
+    assert_eq!(res.unwrap_err(), Error::SharingDuplicateIdentifier);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn split_secret_for_ids() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    let ids = [2u64, 5, 9].map(|i| IdentifierPrimeField::from(Scalar::from(i)));
+
+    let (shares, verifiers) = feldman::split_secret_for_ids::<
+        TestShare<Scalar>,
+        ValueGroup<ProjectivePoint>,
+    >(2, &secret, None, &ids, &mut rng)
+    .unwrap();
+    assert_eq!(shares.len(), 3);
+    for (share, id) in shares.iter().zip(&ids) {
+        assert_eq!(share.identifier(), id);
+        assert!(verifiers.verify_share(share).is_ok());
+    }
+    assert_eq!((&shares[..2]).combine().unwrap(), secret);
+    assert_eq!((&shares[1..]).combine().unwrap(), secret);
+
+    let res = pedersen::split_secret_for_ids::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>(
+        2, &secret, None, None, None, &ids, &mut rng,
+    )
+    .unwrap();
+    assert_eq!(res.secret_shares().len(), 3);
+    for ((share, blinder), id) in res
+        .secret_shares()
+        .iter()
+        .zip(res.blinder_shares())
+        .zip(&ids)
+    {
+        assert_eq!(share.identifier(), id);
+        assert!(res
+            .pedersen_verifier_set()
+            .verify_share_and_blinder(share, blinder)
+            .is_ok());
+    }
+    assert_eq!((&res.secret_shares()[1..]).combine().unwrap(), secret);
+
+    let duplicate = [ids[0], ids[1], ids[0]];
+    let res = feldman::split_secret_for_ids::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>(
+        2, &secret, None, &duplicate, &mut rng,
+    );
+    assert_eq!(res.unwrap_err(), Error::SharingDuplicateIdentifier);
+    let zero = [ids[0], IdentifierPrimeField::ZERO, ids[2]];
+    let res = pedersen::split_secret_for_ids::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>(
+        2, &secret, None, None, None, &zero, &mut rng,
+    );
+    assert_eq!(res.unwrap_err(), Error::SharingInvalidIdentifier);
+    let res = feldman::split_secret_for_ids::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>(
+        3,
+        &secret,
+        None,
+        &ids[..2],
+        &mut rng,
+    );
+    assert_eq!(res.unwrap_err(), Error::SharingLimitLessThanThreshold);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn split_secret_redundant() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    let mut dealings = feldman::split_secret_redundant::<
+        TestShare<Scalar>,
+        ValueGroup<ProjectivePoint>,
+    >(2, 3, &secret, 2, &mut rng)
+    .unwrap();
+    assert_eq!(dealings.len(), 2);
+    assert_ne!(dealings[0].0, dealings[1].0);
+    assert_eq!(feldman::cross_validate_dealings(&dealings).unwrap(), secret);
+
+    // A dealing of a different secret is internally consistent but does not match
+    let other = feldman::split_secret::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>(
+        2,
+        3,
+        &IdentifierPrimeField::from(Scalar::random(&mut rng)),
+        None,
+        &mut rng,
+    )
+    .unwrap();
+    let mismatched = [dealings[0].clone(), other];
+    assert_eq!(
+        feldman::cross_validate_dealings(&mismatched).unwrap_err(),
+        Error::InconsistentDealings
+    );
+
+    *dealings[1].0[0].value_mut().as_mut() += Scalar::ONE;
+    assert_eq!(
+        feldman::cross_validate_dealings(&dealings).unwrap_err(),
+        Error::InvalidShare
+    );
+
+    let res = feldman::split_secret_redundant::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>(
+        2, 3, &secret, 0, &mut rng,
+    );
+    assert_eq!(res.unwrap_err(), Error::InvalidSizeRequest);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn tuple_share_element() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    type Pair = TupleShareElement<IdentifierPrimeField<Scalar>, IdentifierPrimeField<Scalar>>;
+
+    let mut rng = MockRng::default();
+    let a = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    let b = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    let secret = Pair::new(a, b);
+    let shares =
+        Pair::split_secret::<IdentifierPrimeField<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+    assert_eq!(shares.len(), 5);
+
+    let res = Pair::combine(&shares[..3]).unwrap();
+    assert_eq!(res, secret);
+    assert_eq!(res.0 .0, a);
+    assert_eq!(res.0 .1, b);
+    assert_eq!(Pair::combine(&shares[2..]).unwrap(), secret);
+
+    // Components are dealt independently
+    let first = shares[0].1 .0 .0 .0 - shares[0].1 .0 .1 .0;
+    let second = shares[1].1 .0 .0 .0 - shares[1].1 .0 .1 .0;
+    assert_ne!(first, a.0 - b.0);
+    assert_ne!(first, second);
+
+    let bytes = shares[0].1.to_vec();
+    assert_eq!(bytes.len(), 64);
+    assert_eq!(Pair::from_slice(&bytes).unwrap(), shares[0].1);
+    assert!(Pair::from_slice(&bytes[..16]).is_err());
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn refresh_shares() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    let shares = shamir::split_secret::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+    let refreshed = refresh::refresh_shares(&shares, 3, &mut rng).unwrap();
+    assert_eq!(refreshed.len(), shares.len());
+    for (old, new) in shares.iter().zip(&refreshed) {
+        assert_eq!(old.identifier(), new.identifier());
+        assert_ne!(old.value(), new.value());
+    }
+    assert_eq!((&shares[..3]).combine().unwrap(), secret);
+    assert_eq!((&refreshed[..3]).combine().unwrap(), secret);
+    assert_eq!((&refreshed[2..]).combine().unwrap(), secret);
+    // Mixing old and new shares no longer reconstructs
+    let mixed = [shares[0], shares[1], refreshed[2]];
+    assert_ne!(mixed.combine().unwrap(), secret);
+
+    let duplicate = [shares[0], shares[1], shares[0]];
+    assert_eq!(
+        refresh::refresh_shares(&duplicate, 2, &mut rng).unwrap_err(),
+        Error::SharingDuplicateIdentifier
+    );
+    let zero = [
+        shares[0],
+        (IdentifierPrimeField::ZERO, shares[1].1),
+        shares[2],
+    ];
+    assert_eq!(
+        refresh::refresh_shares(&zero, 2, &mut rng).unwrap_err(),
+        Error::SharingInvalidIdentifier
+    );
+    assert_eq!(
+        refresh::refresh_shares(&shares[..2], 3, &mut rng).unwrap_err(),
+        Error::SharingLimitLessThanThreshold
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn reshare() {
+    use crate::tests::utils::MockRng;
+    use core::num::NonZeroUsize;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    let old_shares = shamir::split_secret::<TestShare<Scalar>>(2, 3, &secret, &mut rng).unwrap();
+
+    let generators = [ParticipantIdGeneratorType::sequential(
+        Some(IdentifierPrimeField::from(Scalar::from(10u64))),
+        None,
+        NonZeroUsize::new(5).unwrap(),
+    )];
+    let collection = ParticipantIdGeneratorCollection::from(&generators);
+    let sub_shares = refresh::reshare(&old_shares[1..], 2, 3, &collection, &mut rng).unwrap();
+    assert_eq!(sub_shares.len(), 5);
+
+    let old_ids = old_shares[1..].iter().map(|s| s.0).collect::<Vec<_>>();
+    let new_shares = sub_shares
+        .iter()
+        .map(|subs| {
+            assert_eq!(subs.len(), 2);
+            refresh::combine_reshared(&old_ids, subs).unwrap()
+        })
+        .collect::<Vec<_>>();
+    for (i, share) in new_shares.iter().enumerate() {
+        assert_eq!(
+            share.0,
+            IdentifierPrimeField::from(Scalar::from(10u64 + i as u64))
+        );
+    }
+    assert_eq!((&new_shares[..3]).combine().unwrap(), secret);
+    assert_eq!((&new_shares[2..]).combine().unwrap(), secret);
+    // The new polynomial has degree 2 so two shares are not enough
+    assert_ne!((&new_shares[..2]).combine().unwrap(), secret);
+
+    let res = refresh::reshare(&old_shares[..1], 2, 3, &collection, &mut rng);
+    assert_eq!(res.unwrap_err(), Error::SharingMinThreshold);
+    let res = refresh::combine_reshared(&old_ids, &[sub_shares[0][0], sub_shares[1][1]]);
+    assert_eq!(res.unwrap_err(), Error::SharingInvalidIdentifier);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn split_secret_compact() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let shares = shamir::split_secret_compact(3, 5, &secret, &mut rng).unwrap();
+    for (i, (id, value)) in shares.iter().enumerate() {
+        assert_eq!(id, &[i as u8 + 1]);
+        assert_eq!(value.to_vec().len(), 32);
+    }
+    let subset = shares[1..4]
+        .iter()
+        .map(|(id, value)| (&id[..], *value))
+        .collect::<Vec<_>>();
+    assert_eq!(shamir::combine_widened(&subset).unwrap(), secret);
+
+    let shares = shamir::split_secret_compact(2, 256, &secret, &mut rng).unwrap();
+    assert!(shares.iter().all(|(id, _)| id.len() == 2));
+    assert_eq!(shares[255].0, [1, 0]);
+    let subset = [
+        (&shares[0].0[..], shares[0].1),
+        (&shares[255].0[..], shares[255].1),
+    ];
+    assert_eq!(shamir::combine_widened(&subset).unwrap(), secret);
+}
+
+#[cfg(all(feature = "primitive", any(feature = "alloc", feature = "std")))]
+#[test]
+fn combine_widened() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let shares = shamir::split_secret::<TestShare<Scalar>>(3, 3, &secret, &mut rng).unwrap();
+
+    let id1 = IdentifierU16::from(1u16).serialize();
+    let id2 = IdentifierU32::from(2u32).serialize();
+    let id3 = IdentifierU16::from(3u16).serialize();
+    assert_eq!(id1.len(), 2);
+    assert_eq!(id2.len(), 4);
+    let mixed = [
+        (&id1[..], shares[0].1),
+        (&id2[..], shares[1].1),
+        (&id3[..], shares[2].1),
+    ];
+    assert_eq!(shamir::combine_widened(&mixed).unwrap(), secret);
+
+    // The same participant in two widths is a duplicate
+    let id1_wide = IdentifierU32::from(1u32).serialize();
+    let duplicate = [(&id1[..], shares[0].1), (&id1_wide[..], shares[0].1)];
+    assert_eq!(
+        shamir::combine_widened(&duplicate).unwrap_err(),
+        Error::SharingDuplicateIdentifier
+    );
+    let empty = [(&[][..], shares[0].1), (&id2[..], shares[1].1)];
+    assert_eq!(
+        shamir::combine_widened(&empty).unwrap_err(),
+        Error::SharingInvalidIdentifier
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn pedersen_verify_reconstructed() {
+    use crate::tests::utils::MockRng;
+
+    type V = ValueGroup<ProjectivePoint>;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let res =
+        pedersen::split_secret::<TestShare<Scalar>, V>(3, 5, &secret, None, None, None, &mut rng)
+            .unwrap();
+    let verifiers = res.pedersen_verifier_set();
+    let reconstructed = (&res.secret_shares()[..3]).combine().unwrap();
+    let blinder = (&res.blinder_shares()[2..]).combine().unwrap();
+    assert_eq!(blinder, *res.blinder());
+    assert!(
+        PedersenVerifierSet::<TestShare<Scalar>, V>::verify_reconstructed(
+            verifiers,
+            &reconstructed,
+            &blinder
+        )
+        .is_ok()
+    );
+
+    let wrong_blinder = IdentifierPrimeField(blinder.0 + Scalar::ONE);
+    assert_eq!(
+        PedersenVerifierSet::<TestShare<Scalar>, V>::verify_reconstructed(
+            verifiers,
+            &reconstructed,
+            &wrong_blinder
+        ),
+        Err(Error::InvalidSecret)
+    );
+    assert_eq!(
+        PedersenVerifierSet::<TestShare<Scalar>, V>::verify_reconstructed(
+            verifiers,
+            &blinder,
+            &reconstructed
+        ),
+        Err(Error::InvalidSecret)
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn verify_feldman_pedersen_consistency() {
+    use crate::tests::utils::MockRng;
+
+    type V = ValueGroup<ProjectivePoint>;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let res =
+        pedersen::split_secret::<TestShare<Scalar>, V>(3, 5, &secret, None, None, None, &mut rng)
+            .unwrap();
+    let feldman = res.feldman_verifier_set();
+    let pedersen = res.pedersen_verifier_set();
+    assert!(
+        pedersen::verify_feldman_pedersen_consistency::<TestShare<Scalar>, V>(feldman, pedersen)
+            .is_ok()
+    );
+
+    let other =
+        pedersen::split_secret::<TestShare<Scalar>, V>(4, 5, &secret, None, None, None, &mut rng)
+            .unwrap();
+    assert_eq!(
+        pedersen::verify_feldman_pedersen_consistency::<TestShare<Scalar>, V>(
+            other.feldman_verifier_set(),
+            pedersen
+        ),
+        Err(Error::InvalidCommitmentCount {
+            expected: 3,
+            actual: 4
+        })
+    );
+
+    // Feldman commitments passed off as Pedersen commitments have no blinder part
+    let h = PedersenVerifierSet::<TestShare<Scalar>, V>::blinder_generator(pedersen);
+    let unblinded = <Vec<V> as PedersenVerifierSet<TestShare<Scalar>, V>>::pedersen_set_with_generators_and_verifiers(
+        V::generator(),
+        h,
+        FeldmanVerifierSet::<TestShare<Scalar>, V>::verifiers(feldman),
+    )
+    .unwrap();
+    assert_eq!(
+        pedersen::verify_feldman_pedersen_consistency::<TestShare<Scalar>, V>(feldman, &unblinded),
+        Err(Error::InvalidShare)
+    );
+
+    let wrong_generator = <Vec<V> as PedersenVerifierSet<TestShare<Scalar>, V>>::pedersen_set_with_generators_and_verifiers(
+        h,
+        V::generator(),
+        PedersenVerifierSet::<TestShare<Scalar>, V>::blind_verifiers(pedersen),
+    )
+    .unwrap();
+    assert!(matches!(
+        pedersen::verify_feldman_pedersen_consistency::<TestShare<Scalar>, V>(
+            feldman,
+            &wrong_generator
+        ),
+        Err(Error::InvalidGenerator(_))
+    ));
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn verify_pedersen_dealing_bytes() {
+    use crate::tests::utils::MockRng;
+
+    type V = ValueGroup<ProjectivePoint>;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let res =
+        pedersen::split_secret::<TestShare<Scalar>, V>(3, 5, &secret, None, None, None, &mut rng)
+            .unwrap();
+    let feldman = res
+        .feldman_verifier_set()
+        .iter()
+        .map(|v| v.to_vec())
+        .collect::<Vec<_>>();
+    let pedersen = res
+        .pedersen_verifier_set()
+        .iter()
+        .map(|v| v.to_vec())
+        .collect::<Vec<_>>();
+    let to_bytes = |shares: &[TestShare<Scalar>]| {
+        shares
+            .iter()
+            .map(|s| (s.identifier().to_vec(), s.value().to_vec()))
+            .collect::<Vec<_>>()
+    };
+    let secrets = to_bytes(res.secret_shares());
+    let mut blinders = to_bytes(res.blinder_shares());
+    fn as_refs(v: &[Vec<u8>]) -> Vec<&[u8]> {
+        v.iter().map(|b| b.as_slice()).collect()
+    }
+    fn pairs(v: &[(Vec<u8>, Vec<u8>)]) -> Vec<(&[u8], &[u8])> {
+        v.iter()
+            .map(|(i, s)| (i.as_slice(), s.as_slice()))
+            .collect()
+    }
+    let verify = |feldman: &[Vec<u8>], pedersen: &[Vec<u8>], blinders: &[(Vec<u8>, Vec<u8>)]| {
+        pedersen::verify_pedersen_dealing_bytes::<TestShare<Scalar>, V>(
+            3,
+            &as_refs(feldman),
+            &as_refs(pedersen),
+            &pairs(&secrets),
+            &pairs(blinders),
+        )
+    };
+    assert!(verify(&feldman, &pedersen, &blinders).is_ok());
+
+    assert_eq!(
+        verify(&feldman[..3], &pedersen, &blinders),
+        Err(Error::InvalidCommitmentCount {
+            expected: 4,
+            actual: 3
+        })
+    );
+
+    let mut bad_point = pedersen.clone();
+    bad_point[3] = vec![0xFF; bad_point[3].len()];
+    assert!(verify(&feldman, &bad_point, &blinders).is_err());
+
+    blinders[2].1 = blinders[1].1.clone();
+    assert_eq!(
+        verify(&feldman, &pedersen, &blinders),
+        Err(Error::ShareAtIndexInvalid(2))
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn combine_with_error_detection() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let mut shares = shamir::split_secret::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+    assert_eq!(shares.combine_with_error_detection(3).unwrap(), secret);
+    assert_eq!(
+        (&shares[..2]).combine_with_error_detection(3),
+        Err(Error::NotEnoughShareIdentifiers)
+    );
+
+    let original = shares[4];
+    shares[4].1 = IdentifierPrimeField(shares[4].1 .0 + Scalar::ONE);
+    assert_eq!(
+        shares.combine_with_error_detection(3),
+        Err(Error::InvalidShare)
+    );
+    // A corrupted share in the quorum is detected by the remaining shares
+    shares[4] = original;
+    shares[0].1 = IdentifierPrimeField(shares[0].1 .0 + Scalar::ONE);
+    assert_eq!(
+        shares.combine_with_error_detection(3),
+        Err(Error::InvalidShare)
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn combine_with_correction() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let mut shares = shamir::split_secret::<TestShare<Scalar>>(2, 5, &secret, &mut rng).unwrap();
+    assert_eq!(
+        shares.combine_with_correction(2, 1).unwrap(),
+        (secret, Vec::new())
+    );
+    assert_eq!(
+        shares.combine_with_correction(2, 2),
+        Err(Error::NotEnoughShareIdentifiers)
+    );
+
+    shares[0].1 = IdentifierPrimeField(shares[0].1 .0 + Scalar::ONE);
+    assert_eq!(
+        shares.combine_with_correction(2, 1).unwrap(),
+        (secret, vec![0])
+    );
+    assert_ne!(shares.combine().unwrap(), secret);
+
+    shares[3].1 = IdentifierPrimeField(shares[3].1 .0 + Scalar::ONE);
+    assert_eq!(
+        shares.combine_with_correction(2, 1),
+        Err(Error::InvalidShare)
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn count_consistent_groups() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let a = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let b = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let a_shares = shamir::split_secret::<TestShare<Scalar>>(3, 5, &a, &mut rng).unwrap();
+    let b_shares = shamir::split_secret::<TestShare<Scalar>>(3, 5, &b, &mut rng).unwrap();
+    assert_eq!(shamir::count_consistent_groups(&a_shares, 3), Ok(1));
+
+    let mut pool = a_shares.clone();
+    pool.extend_from_slice(&b_shares[..4]);
+    assert_eq!(shamir::count_consistent_groups(&pool, 3), Ok(2));
+
+    let interleaved = a_shares
+        .iter()
+        .zip(&b_shares)
+        .flat_map(|(a, b)| [*b, *a])
+        .collect::<Vec<_>>();
+    assert_eq!(shamir::count_consistent_groups(&interleaved, 3), Ok(2));
+    assert_eq!(
+        shamir::count_consistent_groups(&a_shares[..2], 3),
+        Err(Error::NotEnoughShareIdentifiers)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn scrub_clears_on_drop() {
+    use std::cell::RefCell;
+
+    std::thread_local! {
+        static DROPPED: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+    }
+
+    // Records the value held at each drop
+    #[derive(Default)]
+    struct Recorded(u64);
+
+    impl Drop for Recorded {
+        fn drop(&mut self) {
+            DROPPED.with(|d| d.borrow_mut().push(self.0));
+        }
+    }
+
+    {
+        let local = Scrub(Recorded(42));
+        assert_eq!(local.0 .0, 42);
+    }
+    // The secret is dropped then its slot is overwritten with zero
+    DROPPED.with(|d| assert_eq!(*d.borrow(), [42, 0]));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn combine_scrubs_intermediates() {
+    use crate::tests::utils::MockRng;
+    use crate::util::SCRUBBED;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let shares = shamir::split_secret::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+
+    // Each lagrange term scrubs at least its numerator, denominator and product
+    let before = SCRUBBED.with(|c| c.get());
+    assert_eq!((&shares[..3]).combine().unwrap(), secret);
+    assert!(SCRUBBED.with(|c| c.get()) - before >= 3 * 3);
+
+    let before = SCRUBBED.with(|c| c.get());
+    assert_eq!((&shares[..3]).combine_copy().unwrap(), secret);
+    assert!(SCRUBBED.with(|c| c.get()) - before >= 3 * 3);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn pending_verifier() {
+    use crate::tests::utils::MockRng;
+
+    type V = ValueGroup<ProjectivePoint>;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let (mut shares, verifier_set) =
+        feldman::split_secret::<TestShare<Scalar>, V>(2, 4, &secret, None, &mut rng).unwrap();
+    shares[1].1 = IdentifierPrimeField(shares[1].1 .0 + Scalar::ONE);
+
+    let mut verifier = feldman::PendingVerifier::<TestShare<Scalar>, V>::new();
+    for share in &shares[..3] {
+        assert_eq!(verifier.add_share(*share), None);
+    }
+    assert_eq!(verifier.pending().len(), 3);
+    assert!(verifier.verifier_set().is_none());
+
+    let results = verifier.set_commitments(verifier_set.clone()).unwrap();
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+    assert!(verifier.pending().is_empty());
+
+    // Shares after the commitments are verified immediately
+    assert_eq!(verifier.add_share(shares[3]), Some(Ok(())));
+    assert_eq!(
+        verifier.set_commitments(verifier_set.clone()).unwrap_err(),
+        Error::InvalidSizeRequest
+    );
+
+    // Malformed sets are rejected without losing the buffered shares
+    let mut verifier = feldman::PendingVerifier::<TestShare<Scalar>, V>::new();
+    assert_eq!(verifier.add_share(shares[0]), None);
+    for set in [vec![], vec![verifier_set[0]]] {
+        assert_eq!(
+            verifier.set_commitments(set).unwrap_err(),
+            Error::InvalidGenerator("verifier set is empty")
+        );
+    }
+    assert_eq!(verifier.pending().len(), 1);
+    assert!(verifier.verifier_set().is_none());
+    let results = verifier.set_commitments(verifier_set).unwrap();
+    assert_eq!(results, [Ok(())]);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn degenerate_shares_error() {
+    use crate::tests::utils::MockRng;
+
+    type V = ValueGroup<ProjectivePoint>;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let empty = TestShare::<Scalar>::default();
+    assert_eq!(empty.try_identifier(), Err(Error::InvalidShare));
+    assert_eq!(empty.try_value(), Err(Error::InvalidShare));
+
+    let shares = shamir::split_secret::<TestShare<Scalar>>(2, 3, &secret, &mut rng).unwrap();
+    assert_eq!(shares[0].try_identifier(), Ok(&shares[0].0));
+    assert_eq!(shares[0].try_value(), Ok(&shares[0].1));
+    assert_eq!(
+        [shares[0], empty].combine(),
+        Err(Error::SharingInvalidIdentifier)
+    );
+
+    let (shares, verifiers) =
+        feldman::split_secret::<TestShare<Scalar>, V>(2, 3, &secret, None, &mut rng).unwrap();
+    assert!(
+        FeldmanVerifierSet::<TestShare<Scalar>, V>::verify_share(&verifiers, &shares[0]).is_ok()
+    );
+    assert_eq!(
+        FeldmanVerifierSet::<TestShare<Scalar>, V>::verify_share(&verifiers, &empty),
+        Err(Error::InvalidShare)
+    );
+
+    let res =
+        pedersen::split_secret::<TestShare<Scalar>, V>(2, 3, &secret, None, None, None, &mut rng)
+            .unwrap();
+    let pedersen = res.pedersen_verifier_set();
+    let share = res.secret_shares()[0];
+    let blinder = res.blinder_shares()[0];
+    assert_eq!(
+        PedersenVerifierSet::<TestShare<Scalar>, V>::verify_share_and_blinder(
+            pedersen, &share, &empty
+        ),
+        Err(Error::InvalidShare)
+    );
+    assert_eq!(
+        PedersenVerifierSet::<TestShare<Scalar>, V>::verify_share_and_blinder(
+            pedersen, &empty, &blinder
+        ),
+        Err(Error::InvalidShare)
+    );
+
+    // Byte shares with no identifier or value
+    assert_eq!(
+        Gf256::combine_array([Vec::new(), Vec::new()]),
+        Err(Error::InvalidShare)
+    );
+    assert_eq!(
+        Gf256::combine_array([vec![1u8, 2], Vec::new()]),
+        Err(Error::InvalidShareLength {
+            index: 1,
+            expected: 2,
+            actual: 0
+        })
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn split_secret_deterministic() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let split = |domain: &[u8]| {
+        shamir::split_secret_deterministic::<TestShare<Scalar>>(3, 5, &secret, domain).unwrap()
+    };
+    let shares = split(b"wallet-backup");
+    assert_eq!(shares, split(b"wallet-backup"));
+    assert_eq!((&shares[1..4]).combine().unwrap(), secret);
+
+    let other = split(b"wallet-recovery");
+    assert_ne!(shares, other);
+    assert_eq!((&other[..3]).combine().unwrap(), secret);
+    // A higher threshold or limit must not reuse any coefficients
+    let coefficients = shamir::combine_polynomial(&shares[..3]).unwrap();
+    for (threshold, limit) in [(4, 5), (3, 6)] {
+        let other = shamir::split_secret_deterministic::<TestShare<Scalar>>(
+            threshold,
+            limit,
+            &secret,
+            b"wallet-backup",
+        )
+        .unwrap();
+        let other_coefficients = shamir::combine_polynomial(&other[..threshold]).unwrap();
+        assert_eq!(other_coefficients[0], secret);
+        for c in &coefficients[1..] {
+            assert!(!other_coefficients[1..].contains(c));
+        }
+    }
+    assert_eq!(
+        shamir::split_secret_deterministic::<TestShare<Scalar>>(1, 5, &secret, b"wallet-backup"),
+        Err(Error::SharingMinThreshold)
+    );
+}
+
+#[test]
+fn default_share_add_sub() {
+    type Share = DefaultShare<IdentifierPrimeField<Scalar>, IdentifierPrimeField<Scalar>>;
+
+    let a = Share::from((Scalar::from(3u64), Scalar::from(10u64)));
+    let b = Share::from((Scalar::from(3u64), Scalar::from(32u64)));
+    let sum = a + b;
+    assert_eq!(sum, Share::from((Scalar::from(3u64), Scalar::from(42u64))));
+    assert_eq!(sum - b, a);
+    assert_eq!(a.checked_add(&b).unwrap(), a + b);
+
+    let c = Share::from((Scalar::from(4u64), Scalar::from(32u64)));
+    assert_eq!(
+        a.checked_add(&c).unwrap_err(),
+        Error::SharingInvalidIdentifier
+    );
+    assert_eq!(
+        a.checked_sub(&c).unwrap_err(),
+        Error::SharingInvalidIdentifier
+    );
+}
+
+#[test]
+#[should_panic(expected = "cannot add shares with different identifiers")]
+fn default_share_add_mismatched_identifiers() {
+    type Share = DefaultShare<IdentifierPrimeField<Scalar>, IdentifierPrimeField<Scalar>>;
+
+    let a = Share::from((Scalar::from(3u64), Scalar::from(10u64)));
+    let b = Share::from((Scalar::from(4u64), Scalar::from(32u64)));
+    let _ = a + b;
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn pedersen_reuse_feldman_commitments() {
+    use crate::pedersen::{PedersenOptions, StdPedersenResult};
+    use crate::tests::utils::MockRng;
+
+    type Result = StdPedersenResult<TestShare<Scalar>, ValueGroup<ProjectivePoint>>;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let blinder = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let h = ValueGroup::<ProjectivePoint>::random(&mut rng);
+    let (_, commitments) = feldman::split_secret::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>(
+        3,
+        5,
+        &secret,
+        None,
+        MockRng::default(),
+    )
+    .unwrap();
+
+    let mut options = PedersenOptions {
+        secret,
+        blinder: Some(blinder),
+        secret_generator: None,
+        blinder_generator: Some(h),
+        participant_generators: &[ParticipantIdGeneratorType::default()],
+        reuse_feldman_commitments: Some(&commitments[1..]),
+    };
+    // Same rng state so the secret polynomial matches the Feldman dealing
+    let res =
+        Result::split_secret_with_blind_verifiers(3, 5, &options, MockRng::default()).unwrap();
+    assert_eq!(
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::verifiers(res.feldman_verifier_set()),
+        &commitments[1..]
+    );
+    for (s, b) in res.secret_shares().iter().zip(res.blinder_shares()) {
+        assert!(res
+            .pedersen_verifier_set()
+            .verify_share_and_blinder(s, b)
+            .is_ok());
+    }
+
+    // A fresh polynomial does not match the commitments
+    let err = Result::split_secret_with_blind_verifiers(3, 5, &options, &mut rng).unwrap_err();
+    assert!(matches!(err, Error::InvalidGenerator(_)));
+
+    let mut tampered = commitments[1..].to_vec();
+    tampered[2] += ValueGroup::<ProjectivePoint>::generator();
+    options.reuse_feldman_commitments = Some(&tampered);
+    let err =
+        Result::split_secret_with_blind_verifiers(3, 5, &options, MockRng::default()).unwrap_err();
+    assert!(matches!(err, Error::InvalidGenerator(_)));
+
+    options.reuse_feldman_commitments = Some(&commitments[1..3]);
+    let err =
+        Result::split_secret_with_blind_verifiers(3, 5, &options, MockRng::default()).unwrap_err();
+    assert_eq!(
+        err,
+        Error::InvalidCommitmentCount {
+            expected: 3,
+            actual: 2
+        }
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn nonzero_scalar_value() {
+    use crate::tests::utils::MockRng;
+    use k256::Secp256k1;
+
+    type NonZeroShare = (IdentifierPrimeField<Scalar>, NonZeroScalarValue<Scalar>);
+
+    let mut rng = MockRng::default();
+    let sk = NonZeroScalar::random(&mut rng);
+    let secret = NonZeroScalarValue::from_nonzero_scalar(sk);
+    let shares = NonZeroScalarValue::split_secret::<NonZeroShare>(2, 3, &secret, &mut rng).unwrap();
+    let res = NonZeroScalarValue::combine(&shares[1..]).unwrap();
+    assert_eq!(res, secret);
+    assert_eq!(*res.to_nonzero_scalar::<Secp256k1>().unwrap(), *sk);
+
+    let random = NonZeroScalarValue::<Scalar>::random(&mut rng);
+    assert!(!bool::from(random.is_zero()));
+    assert!(random.to_nonzero_scalar::<Secp256k1>().is_ok());
+    assert_eq!(NonZeroScalarValue::new(*sk).unwrap(), secret);
+    assert!(bool::from(NonZeroScalarValue::new(Scalar::ZERO).is_none()));
+
+    // A zero secret is rejected by split and combine
+    let zero = NonZeroScalarValue::<Scalar>::zero();
+    assert_eq!(
+        NonZeroScalarValue::split_secret::<NonZeroShare>(2, 3, &zero, &mut rng),
+        Err(Error::InvalidSecret)
+    );
+    let zero_shares = shamir::split_secret::<NonZeroShare>(2, 3, &zero, &mut rng).unwrap();
+    assert_eq!(
+        NonZeroScalarValue::combine(&zero_shares),
+        Err(Error::InvalidSecret)
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn combine_constant_time() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    for (threshold, limit) in [(2, 3), (3, 5), (5, 7), (7, 10)] {
+        let secret = IdentifierPrimeField(Scalar::random(&mut rng));
+        let shares =
+            shamir::split_secret::<TestShare<Scalar>>(threshold, limit, &secret, &mut rng).unwrap();
+        for start in 0..=limit - threshold {
+            let subset = &shares[start..start + threshold];
+            assert_eq!(subset.combine_constant_time().unwrap(), secret);
+            assert_eq!(
+                subset.combine_constant_time().unwrap(),
+                subset.combine().unwrap()
+            );
+        }
+        assert_eq!(shares.combine_constant_time().unwrap(), secret);
+    }
+
+    let secret = IdentifierPrimeField(Scalar::random(&mut rng));
+    let mut shares = shamir::split_secret::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+    assert_eq!(
+        (&shares[..1]).combine_constant_time().unwrap_err(),
+        Error::SharingMinThreshold
+    );
+    shares[1].0 = shares[3].0;
+    assert_eq!(
+        shares.combine_constant_time().unwrap_err(),
+        Error::SharingDuplicateIdentifier
+    );
+    shares[1].0 = IdentifierPrimeField::ZERO;
+    assert_eq!(
+        shares.combine_constant_time().unwrap_err(),
+        Error::SharingInvalidIdentifier
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn combine_try_from_iter() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField(Scalar::random(&mut rng));
+    let shares = shamir::split_secret::<TestShare<Scalar>>(2, 3, &secret, &mut rng).unwrap();
+
+    let res = shamir::combine_try_from_iter(shares.iter().map(|s| Ok::<_, &str>(*s)));
+    assert_eq!(res.unwrap(), secret);
+
+    let source = [Ok(shares[0]), Err("connection reset"), Ok(shares[2])];
+    let res = shamir::combine_try_from_iter(source);
+    assert_eq!(
+        res.unwrap_err(),
+        CombineOrSourceError::Source("connection reset")
+    );
+
+    let source = [Ok::<_, &str>(shares[0]), Ok(shares[0])];
+    let res = shamir::combine_try_from_iter(source);
+    assert_eq!(
+        res.unwrap_err(),
+        CombineOrSourceError::Combine(Error::SharingDuplicateIdentifier)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_set_keys() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+    use std::collections::HashSet;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField(Scalar::random(&mut rng));
+    let (shares, verifiers) =
+        feldman::split_secret::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>(
+            3, 5, &secret, None, &mut rng,
+        )
+        .unwrap();
+
+    let mut seen = HashSet::new();
+    for v in &verifiers {
+        assert!(seen.insert(*v));
+    }
+    assert!(!seen.insert(verifiers[1]));
+    assert!(!seen.insert(ValueGroup(verifiers[1].0 + ProjectivePoint::IDENTITY)));
+    assert_eq!(seen.len(), verifiers.len());
+
+    let mut ids = shares.iter().map(|s| s.0).collect::<HashSet<_>>();
+    assert_eq!(ids.len(), 5);
+    assert!(!ids.insert(IdentifierPrimeField(Scalar::from(3u64))));
+    assert!(ids.insert(IdentifierPrimeField(Scalar::from(6u64))));
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn label_identifier() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    type LabelShare = (LabelIdentifier<Scalar>, IdentifierPrimeField<Scalar>);
+
+    let mut rng = MockRng::default();
+    let labels = ["alice@example.com", "bob@example.com", "carol@example.com"]
+        .map(LabelIdentifier::<Scalar>::new);
+    for label in &labels {
+        assert_eq!(
+            *label.as_ref(),
+            *hash_to_identifier::<IdentifierPrimeField<Scalar>>(label.label().as_bytes())
+        );
+        assert!(!bool::from(label.is_zero()));
+        assert_eq!(
+            LabelIdentifier::from_slice(&label.to_vec()).unwrap(),
+            *label
+        );
+    }
+    assert!(bool::from(LabelIdentifier::<Scalar>::zero().is_zero()));
+    assert!(LabelIdentifier::<Scalar>::from_slice(&[0xff]).is_err());
+
+    let secret = IdentifierPrimeField(Scalar::random(&mut rng));
+    let shares = shamir::split_secret_with_participant_generator::<LabelShare>(
+        2,
+        3,
+        &secret,
+        &mut rng,
+        &[ParticipantIdGeneratorType::list(&labels)],
+    )
+    .unwrap();
+
+    let by_label = |label: &str| {
+        shares
+            .iter()
+            .find(|(id, _)| id.label() == label)
+            .cloned()
+            .unwrap()
+    };
+    let (bob, bob_value) = by_label("bob@example.com");
+    let (carol, carol_value) = by_label("carol@example.com");
+    let received = [
+        (
+            LabelIdentifier::from_slice(&carol.to_vec()).unwrap(),
+            carol_value,
+        ),
+        (
+            LabelIdentifier::from_slice(&bob.to_vec()).unwrap(),
+            bob_value,
+        ),
+    ];
+    assert_eq!((&received[..]).combine().unwrap(), secret);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn group_element_identifier() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    type PointShare = (
+        GroupElementIdentifier<ProjectivePoint>,
+        IdentifierPrimeField<Scalar>,
+    );
+
+    let mut rng = MockRng::default();
+    let ids: [GroupElementIdentifier<ProjectivePoint>; 3] =
+        core::array::from_fn(|_| GroupElementIdentifier::new(ProjectivePoint::random(&mut rng)));
+    for id in &ids {
+        let encoding = id.point().to_bytes();
+        assert_ne!(
+            *id.as_ref(),
+            *hash_to_identifier::<IdentifierPrimeField<Scalar>>(encoding.as_ref())
+        );
+
+        assert!(!bool::from(id.is_zero()));
+        assert_eq!(
+            GroupElementIdentifier::from_slice(&id.to_vec()).unwrap(),
+            *id
+        );
+    }
+    assert!(GroupElementIdentifier::<ProjectivePoint>::from_slice(&[0xff; 33]).is_err());
+    assert!(GroupElementIdentifier::<ProjectivePoint>::from_slice(&[2u8; 3]).is_err());
+
+    let secret = IdentifierPrimeField(Scalar::random(&mut rng));
+    let shares = shamir::split_secret_with_participant_generator::<PointShare>(
+        2,
+        3,
+        &secret,
+        &mut rng,
+        &[ParticipantIdGeneratorType::list(&ids)],
+    )
+    .unwrap();
+    assert_eq!(shares.len(), 3);
+    for (share, id) in shares.iter().zip(&ids) {
+        assert_eq!(share.0.point(), id.point());
+    }
+
+    let received = [
+        (
+            GroupElementIdentifier::<ProjectivePoint>::from_slice(&shares[2].0.to_vec()).unwrap(),
+            shares[2].1,
+        ),
+        (
+            GroupElementIdentifier::<ProjectivePoint>::from_slice(&shares[0].0.to_vec()).unwrap(),
+            shares[0].1,
+        ),
+    ];
+    assert_eq!((&received[..]).combine().unwrap(), secret);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn split_secret_with_polynomial() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let (shares, polynomial) =
+        shamir::split_secret_with_polynomial::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+    assert_eq!(polynomial.len(), 3);
+    assert_eq!(polynomial[0].1, secret);
+    for share in &shares {
+        assert_eq!(polynomial.evaluate(&share.0, 3), share.1);
+    }
+    assert_eq!((&shares[2..]).combine().unwrap(), secret);
+
+    #[cfg(feature = "zeroize")]
+    {
+        let (shares, polynomial) = shamir::split_secret_with_zeroizing_polynomial::<
+            TestShare<Scalar>,
+        >(3, 5, &secret, &mut rng)
+        .unwrap();
+        for share in &shares {
+            assert_eq!(polynomial.evaluate(&share.0, 3), share.1);
+        }
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn assert_crypto_rng() {
+    use rand_core::SeedableRng;
+
+    // XorShiftRng does not implement CryptoRng
+    let mut rng = AssertCryptoRng(rand_xorshift::XorShiftRng::from_seed([7u8; 16]));
+    let secret = IdentifierPrimeField::from(Scalar::from(42u64));
+    let shares = shamir::split_secret::<TestShare<Scalar>>(2, 3, &secret, &mut rng).unwrap();
+    assert_eq!((&shares[1..]).combine().unwrap(), secret);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn reshare_verifiable() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    let (old_shares, old_verifiers) = feldman::split_secret::<
+        TestShare<Scalar>,
+        ValueGroup<ProjectivePoint>,
+    >(2, 3, &secret, None, &mut rng)
+    .unwrap();
+
+    let new_ids = (10..15u64)
+        .map(|i| IdentifierPrimeField::from(Scalar::from(i)))
+        .collect::<Vec<_>>();
+    let (new_shares, new_verifiers) =
+        refresh::reshare_verifiable::<_, ValueGroup<ProjectivePoint>>(
+            &old_shares[1..],
+            3,
+            &new_ids,
+            None,
+            &mut rng,
+        )
+        .unwrap();
+    assert_eq!(new_shares.len(), 5);
+    assert_eq!(new_verifiers.len(), 4);
+    for (share, id) in new_shares.iter().zip(&new_ids) {
+        assert_eq!(share.0, *id);
+        assert!(new_verifiers.verify_share(share).is_ok());
+        assert!(old_verifiers.verify_share(share).is_err());
+    }
+    assert_eq!(
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::public_key(&new_verifiers).unwrap(),
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::public_key(&old_verifiers).unwrap()
+    );
+    assert_eq!((&new_shares[..3]).combine().unwrap(), secret);
+    assert_eq!((&new_shares[2..]).combine().unwrap(), secret);
+
+    let mut bad = new_shares[0];
+    *bad.1 += Scalar::ONE;
+    assert!(new_verifiers.verify_share(&bad).is_err());
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn combine_nonzero() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    let zero = IdentifierPrimeField::<Scalar>::ZERO;
+    let shares = shamir::split_secret::<TestShare<Scalar>>(2, 3, &zero, &mut rng).unwrap();
+    assert_eq!((&shares[..2]).combine().unwrap(), zero);
+    assert_eq!(
+        (&shares[..2]).combine_nonzero().unwrap_err(),
+        Error::InvalidSecret
+    );
+
+    let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    let shares = shamir::split_secret::<TestShare<Scalar>>(2, 3, &secret, &mut rng).unwrap();
+    assert_eq!((&shares[1..]).combine_nonzero().unwrap(), secret);
+    assert_eq!(
+        (&shares[..1]).combine_nonzero().unwrap_err(),
+        Error::SharingMinThreshold
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn combine_to_point() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    let shares = shamir::split_secret::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+
+    for share in &shares[3..] {
+        let value = (&shares[..3]).combine_to_point(share.identifier()).unwrap();
+        assert_eq!(&value, share.value());
+    }
+    let value = (&shares[2..])
+        .combine_to_point(shares[0].identifier())
+        .unwrap();
+    assert_eq!(&value, shares[0].value());
+    let value = (&shares[..3])
+        .combine_to_point(&IdentifierPrimeField::ZERO)
+        .unwrap();
+    assert_eq!(value, secret);
+
+    // A new participant's share combines with existing ones
+    let id = IdentifierPrimeField::from(Scalar::from(10u64));
+    let value = (&shares[..3]).combine_to_point(&id).unwrap();
+    let new_shares = [shares[4], (id, value), shares[1]];
+    assert_eq!((&new_shares[..]).combine().unwrap(), secret);
+
+    assert_eq!(
+        (&shares[..1]).combine_to_point(&id).unwrap_err(),
+        Error::SharingMinThreshold
+    );
+    let duplicate = [shares[0], shares[1], shares[0]];
+    assert_eq!(
+        (&duplicate[..]).combine_to_point(&id).unwrap_err(),
+        Error::SharingDuplicateIdentifier
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn lagrange_coefficients() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    const ITERATIONS: usize = 100;
+
+    let mut rng = MockRng::default();
+    let dealings = (0..ITERATIONS)
+        .map(|_| {
+            let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+            let shares =
+                shamir::split_secret::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+            (secret, shares)
+        })
+        .collect::<Vec<_>>();
+    let ids = [1, 3, 4].map(|i| dealings[0].1[i].0);
+    let coefficients = LagrangeCoefficients::<TestShare<Scalar>>::new(&ids).unwrap();
+    assert_eq!(coefficients.identifiers(), &ids);
+
+    for (secret, shares) in &dealings {
+        let quorum = [1, 3, 4].map(|i| shares[i]);
+        let values = quorum.map(|s| s.1);
+        assert_eq!(quorum.combine().unwrap(), *secret);
+        assert_eq!(coefficients.combine_values(&values).unwrap(), *secret);
+    }
+
+    assert_eq!(
+        coefficients
+            .combine_values(&[dealings[0].1[0].1])
+            .unwrap_err(),
+        Error::InvalidSizeRequest
+    );
+    assert_eq!(
+        LagrangeCoefficients::<TestShare<Scalar>>::new(&ids[..1]).unwrap_err(),
+        Error::SharingMinThreshold
+    );
+    assert_eq!(
+        LagrangeCoefficients::<TestShare<Scalar>>::new(&[ids[0], ids[1], ids[0]]).unwrap_err(),
+        Error::SharingDuplicateIdentifier
+    );
+    assert_eq!(
+        LagrangeCoefficients::<TestShare<Scalar>>::new(&[ids[0], IdentifierPrimeField::ZERO])
+            .unwrap_err(),
+        Error::SharingInvalidIdentifier
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn combine_with_scratch() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    let mut scratch = CombineScratch::<TestShare<Scalar>>::new();
+    assert_eq!(scratch.capacity(), 0);
+
+    let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    let shares = shamir::split_secret::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+    let res = (&shares[..4]).combine_with_scratch(&mut scratch).unwrap();
+    assert_eq!(res, secret);
+    assert_eq!(res, (&shares[..4]).combine().unwrap());
+    let capacity = scratch.capacity();
+    assert!(capacity >= 4);
+
+    let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    let shares = shamir::split_secret::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+    let res = (&shares[1..4]).combine_with_scratch(&mut scratch).unwrap();
+    assert_eq!(res, secret);
+    assert_eq!(res, (&shares[1..4]).combine().unwrap());
+    assert_eq!(scratch.capacity(), capacity);
+
+    assert_eq!(
+        (&shares[..1])
+            .combine_with_scratch(&mut scratch)
+            .unwrap_err(),
+        Error::SharingMinThreshold
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn combine_ct_padded() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let shares = shamir::split_secret::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+
+    // A fixed size buffer where only the first `count` slots hold real shares
+    let mut buffer = [TestShare::<Scalar>::default(); 5];
+    buffer[..3].copy_from_slice(&shares[1..4]);
+    let res = buffer.combine_ct(3, 3);
+    assert!(bool::from(res.is_some()));
+    assert_eq!(res.unwrap(), secret);
+    let res = buffer.combine_ct(5, 3);
+    assert!(bool::from(res.is_none()));
+
+    buffer.copy_from_slice(&shares);
+    assert_eq!(buffer.combine_ct(5, 3).unwrap(), secret);
+    assert_eq!(buffer.combine_ct(4, 3).unwrap(), secret);
+
+    // Not enough shares
+    assert!(bool::from(buffer.combine_ct(2, 3).is_none()));
+    assert!(bool::from(buffer.combine_ct(1, 1).is_none()));
+    assert!(bool::from(buffer.combine_ct(0, 3).is_none()));
+    assert!(bool::from(buffer.combine_ct(6, 3).is_none()));
+
+    // Duplicate identifiers among the present shares
+    buffer[1] = buffer[0];
+    assert!(bool::from(buffer.combine_ct(3, 3).is_none()));
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn split_secret_map() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let map = shamir::split_secret_map::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+    assert_eq!(map.len(), 5);
+    for i in 1..=5u64 {
+        assert!(map.contains_key(&IdentifierPrimeField(Scalar::from(i))));
+    }
+
+    let shares = map
+        .iter()
+        .skip(1)
+        .map(|(id, value)| TestShare::with_identifier_and_value(*id, *value))
+        .collect::<Vec<_>>();
+    assert_eq!(shares.combine().unwrap(), secret);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn combine_keeps_zero_identifier_error() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let mut shares = shamir::split_secret::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+    assert_eq!((&shares[..3]).combine().unwrap(), secret);
+
+    shares[2].0 = IdentifierPrimeField::ZERO;
+    assert_eq!(
+        (&shares[..3]).combine().unwrap_err(),
+        Error::SharingInvalidIdentifier
+    );
+    assert_eq!(
+        (&shares[1..4]).combine().unwrap_err(),
+        Error::SharingInvalidIdentifier
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn feldman_find_invalid_shares() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let (mut shares, verifiers) = feldman::split_secret::<
+        TestShare<Scalar>,
+        ValueGroup<ProjectivePoint>,
+    >(3, 5, &secret, None, &mut rng)
+    .unwrap();
+    assert!(FeldmanVerifierSet::<TestShare<Scalar>, _>::verify_shares(&verifiers, &shares).is_ok());
+    assert!(
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::find_invalid_shares(&verifiers, &shares)
+            .is_empty()
+    );
+
+    *shares[1].1 += Scalar::ONE;
+    shares[3].1 = IdentifierPrimeField::<Scalar>::zero();
+    assert_eq!(
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::verify_shares(&verifiers, &shares),
+        Err(Error::ShareAtIndexInvalid(1))
+    );
+    assert_eq!(
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::verify_shares(&verifiers, &shares[2..]),
+        Err(Error::ShareAtIndexInvalid(1))
+    );
+    let invalid =
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::find_invalid_shares(&verifiers, &shares);
+    assert_eq!(invalid, vec![&shares[1], &shares[3]]);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn feldman_verify_batch_indexed() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let (mut shares, verifiers) = feldman::split_secret::<
+        TestShare<Scalar>,
+        ValueGroup<ProjectivePoint>,
+    >(4, 10, &secret, None, &mut rng)
+    .unwrap();
+    let mask =
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::verify_batch_indexed(&verifiers, &shares);
+    assert_eq!(mask, vec![true; 10]);
+
+    *shares[0].1 += Scalar::ONE;
+    shares[4].0 = IdentifierPrimeField::<Scalar>::zero();
+    shares[6].1 = IdentifierPrimeField::<Scalar>::zero();
+    shares[7].0 = IdentifierPrimeField::from(Scalar::from(42u64));
+    let mask =
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::verify_batch_indexed(&verifiers, &shares);
+    let expected = shares
+        .iter()
+        .map(|s| FeldmanVerifierSet::<TestShare<Scalar>, _>::verify_share(&verifiers, s).is_ok())
+        .collect::<Vec<_>>();
+    assert_eq!(mask, expected);
+    assert_eq!(
+        mask,
+        [false, true, true, true, false, true, false, false, true, true]
+    );
+
+    let empty: Vec<ValueGroup<ProjectivePoint>> = Vec::new();
+    assert_eq!(
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::verify_batch_indexed(&empty, &shares[1..3]),
+        [false, false]
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn public_key_from_shares() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let (shares, verifiers) =
+        feldman::split_secret::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>(
+            3, 5, &secret, None, &mut rng,
+        )
+        .unwrap();
+    let generator = ValueGroup::<ProjectivePoint>::generator();
+    let expected = generator * (&shares[1..4]).combine().unwrap();
+
+    let public_key = feldman::public_key_from_shares(&shares[1..4], generator).unwrap();
+    assert_eq!(public_key, expected);
+
+    // Each holder publishes only their public point
+    let points = shares[2..]
+        .iter()
+        .map(|s| (s.0, generator * s.1))
+        .collect::<Vec<_>>();
+    let public_key =
+        feldman::public_key_from_public_shares::<TestShare<Scalar>, _>(&points, generator).unwrap();
+    assert_eq!(public_key, expected);
+    assert_eq!(
+        public_key,
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::public_key(&verifiers).unwrap()
+    );
+
+    assert_eq!(
+        feldman::public_key_from_shares(&shares[..1], generator).unwrap_err(),
+        Error::SharingMinThreshold
+    );
+    assert_eq!(
+        feldman::public_key_from_public_shares::<TestShare<Scalar>, _>(
+            &[points[0], points[0]],
+            generator,
+        )
+        .unwrap_err(),
+        Error::SharingDuplicateIdentifier
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn share_encoded_string() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let shares = shamir::split_secret::<TestShare<Scalar>>(2, 3, &secret, &mut rng).unwrap();
+    for share in &shares {
+        let encoded = share.to_encoded_string();
+        assert!(encoded.starts_with("vsss1:"));
+        assert_eq!(
+            TestShare::<Scalar>::from_encoded_string(&encoded).unwrap(),
+            *share
+        );
+        let encoded = share.to_encoded_hex_string();
+        assert!(encoded.starts_with("vssh1:"));
+        assert_eq!(
+            TestShare::<Scalar>::from_encoded_string(&encoded).unwrap(),
+            *share
+        );
+    }
+
+    // One byte identifiers and values
+    type GfShare = DefaultShare<IdentifierGf256, IdentifierGf256>;
+    let share =
+        GfShare::with_identifier_and_value(IdentifierGf256(Gf256(7)), IdentifierGf256(Gf256(0x2c)));
+    assert_eq!(share.to_encoded_string(), "vsss1:AQAAAAEAAAABByw");
+    assert_eq!(
+        share.to_encoded_hex_string(),
+        "vssh1:010000000100000001072c"
+    );
+    assert_eq!(
+        GfShare::from_encoded_string("vsss1:AQAAAAEAAAABByw").unwrap(),
+        share
+    );
+    assert_eq!(
+        GfShare::from_encoded_string("vssh1:010000000100000001072C").unwrap(),
+        share
+    );
+    // The lengths must match the element sizes
+    assert_eq!(
+        TestShare::<Scalar>::from_encoded_string("vsss1:AQAAAAEAAAABByw").unwrap_err(),
+        Error::InvalidShareElement
+    );
+
+    let encoded = shares[0].to_encoded_string();
+    for truncated in [
+        &encoded[..encoded.len() - 1],
+        &encoded[..encoded.len() - 4],
+        "vsss1:",
+    ] {
+        assert_eq!(
+            TestShare::<Scalar>::from_encoded_string(truncated).unwrap_err(),
+            Error::InvalidShareConversion
+        );
+    }
+    let encoded = shares[0].to_encoded_hex_string();
+    assert_eq!(
+        TestShare::<Scalar>::from_encoded_string(&encoded[..encoded.len() - 2]).unwrap_err(),
+        Error::InvalidShareConversion
+    );
+    assert_eq!(
+        TestShare::<Scalar>::from_encoded_string(&encoded[6..]).unwrap_err(),
+        Error::InvalidShareConversion
+    );
+    assert_eq!(
+        TestShare::<Scalar>::from_encoded_string("vsss1:AQAA*AQA").unwrap_err(),
+        Error::InvalidShareConversion
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn combine_copy() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let mut shares = shamir::split_secret::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+    for quorum in [&shares[..3], &shares[1..4], &shares[..]] {
+        assert_eq!(quorum.combine_copy().unwrap(), quorum.combine().unwrap());
+        assert_eq!(quorum.combine_copy().unwrap(), secret);
+    }
+    assert_eq!(
+        (&shares[..1]).combine_copy().unwrap_err(),
+        Error::SharingMinThreshold
+    );
+    shares[1].0 = IdentifierPrimeField::ZERO;
+    assert_eq!(
+        (&shares[..3]).combine_copy().unwrap_err(),
+        Error::SharingInvalidIdentifier
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[ignore]
+fn combine_copy_benchmark() {
+    use crate::tests::utils::MockRng;
+    use std::time::Instant;
+
+    const ITERATIONS: usize = 1000;
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let shares = shamir::split_secret::<TestShare<Scalar>>(10, 10, &secret, &mut rng).unwrap();
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        assert_eq!(shares.combine().unwrap(), secret);
+    }
+    let cloned = start.elapsed();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        assert_eq!(shares.combine_copy().unwrap(), secret);
+    }
+    let copied = start.elapsed();
+    println!("combine: {:?}, combine_copy: {:?}", cloned, copied);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn shamir_secret_position() {
+    use crate::tests::utils::MockRng;
+    use shamir::SecretPosition;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let shares = shamir::split_secret_with_position::<TestShare<Scalar>>(
+        3,
+        5,
+        &secret,
+        SecretPosition::Leading,
+        &mut rng,
+    )
+    .unwrap();
+    assert_eq!(shares.len(), 5);
+    for quorum in [&shares[..3], &shares[1..4], &shares[2..]] {
+        let polynomial = shamir::combine_polynomial(quorum).unwrap();
+        assert_eq!(polynomial.len(), 3);
+        assert_eq!(polynomial[2], secret);
+        assert_ne!(polynomial[0], secret);
+        assert_eq!(
+            shamir::combine_with_position(quorum, 3, SecretPosition::Leading).unwrap(),
+            secret
+        );
+    }
+    assert_ne!((&shares[..3]).combine().unwrap(), secret);
+    // Extra shares would make the leading coefficient zero
+    assert_eq!(
+        shamir::combine_with_position(&shares[..4], 3, SecretPosition::Leading),
+        Err(Error::InvalidSizeRequest)
+    );
+    assert_eq!(
+        shamir::combine_with_position(&shares[..2], 3, SecretPosition::Leading),
+        Err(Error::InvalidSizeRequest)
+    );
+
+    let shares = shamir::split_secret_with_position::<TestShare<Scalar>>(
+        3,
+        5,
+        &secret,
+        SecretPosition::Constant,
+        &mut rng,
+    )
+    .unwrap();
+    assert_eq!(
+        shamir::combine_with_position(&shares[1..4], 3, SecretPosition::Constant).unwrap(),
+        secret
+    );
+    // Extra shares give zero high degree coefficients
+    let polynomial = shamir::combine_polynomial(&shares[..4]).unwrap();
+    assert_eq!(polynomial[0], secret);
+    assert_eq!(polynomial[3], IdentifierPrimeField::ZERO);
+
+    assert_eq!(
+        shamir::combine_polynomial(&shares[..1]).unwrap_err(),
+        Error::SharingMinThreshold
+    );
+    assert_eq!(
+        shamir::combine_polynomial(&[shares[0], shares[0]]).unwrap_err(),
+        Error::SharingDuplicateIdentifier
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn shamir_weighted() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let bundles =
+        shamir::split_secret_weighted::<TestShare<Scalar>>(3, &[2, 2, 1, 1], &secret, &mut rng)
+            .unwrap();
+    assert_eq!(
+        bundles.iter().map(|b| b.len()).collect::<Vec<_>>(),
+        [2, 2, 1, 1]
+    );
+    // Identifiers are consecutive within and across bundles
+    for (i, share) in bundles.iter().flatten().enumerate() {
+        assert_eq!(share.0, IdentifierPrimeField(Scalar::from(i as u64 + 1)));
+    }
+
+    // Two weight 2 participants
+    assert_eq!(shamir::combine_weighted(&bundles[..2]).unwrap(), secret);
+    assert_eq!(
+        shamir::combine_weighted(&[&bundles[1][..], &bundles[3][..]]).unwrap(),
+        secret
+    );
+    // Weight 2 is not enough
+    assert_ne!(shamir::combine_weighted(&bundles[..1]).unwrap(), secret);
+    assert_ne!(shamir::combine_weighted(&bundles[2..]).unwrap(), secret);
+
+    assert_eq!(
+        shamir::split_secret_weighted::<TestShare<Scalar>>(5, &[2, 2], &secret, &mut rng)
+            .unwrap_err(),
+        Error::SharingMinThreshold
+    );
+    assert_eq!(
+        shamir::split_secret_weighted::<TestShare<Scalar>>(2, &[2, 0], &secret, &mut rng)
+            .unwrap_err(),
+        Error::InvalidSizeRequest
+    );
+}
+
+#[test]
+fn generic_array_pedersen_result_sizes() {
+    use crate::pedersen::{GenericArrayPedersenResult, PedersenOptions};
+    use crate::tests::utils::MockRng;
+    use core::ops::{Add, Sub};
+    use generic_array::{
+        typenum::{Add1, Sub1, Unsigned, B1, U2, U3, U4, U5},
+        ArrayLength,
+    };
+    type Add2<A> = <A as Add<U2>>::Output;
+
+    fn check<T, N>(threshold: usize, limit: usize)
+    where
+        T: Unsigned + Add<B1> + Add<U2> + ArrayLength,
+        N: ArrayLength,
+        Add1<T>: ArrayLength + Sub<B1, Output = T>,
+        Add2<T>: ArrayLength + Sub<U2, Output = T>,
+        Sub1<Add1<T>>: ArrayLength,
+        <Add2<T> as Sub<U2>>::Output: ArrayLength,
+    {
+        let mut rng = MockRng::default();
+        let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+        let participant_generators = [ParticipantIdGeneratorType::default()];
+        let options = PedersenOptions {
+            secret,
+            blinder: None,
+            secret_generator: None,
+            blinder_generator: None,
+            participant_generators: &participant_generators,
+            reuse_feldman_commitments: None,
+        };
+        let res = GenericArrayPedersenResult::<
+            TestShare<Scalar>,
+            ValueGroup<ProjectivePoint>,
+            T,
+            N,
+        >::split_secret_with_blind_verifiers(threshold, limit, &options, &mut rng)
+        .unwrap();
+        assert_eq!(res.feldman_verifier_set().len(), T::USIZE + 1);
+        assert_eq!(res.pedersen_verifier_set().len(), T::USIZE + 2);
+        assert_eq!(res.secret_shares().len(), N::USIZE);
+        assert_eq!(res.blinder_shares().len(), N::USIZE);
+        for (share, blinder) in res.secret_shares().iter().zip(res.blinder_shares().iter()) {
+            assert!(res
+                .pedersen_verifier_set()
+                .verify_share_and_blinder(share, blinder)
+                .is_ok());
+        }
+        assert_eq!(
+            (&res.secret_shares()[..threshold]).combine().unwrap(),
+            secret
+        );
+    }
+
+    check::<U2, U3>(2, 3);
+    check::<U3, U4>(3, 4);
+    check::<U3, U5>(3, 5);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn feldman_deal() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let dealing = feldman::deal::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>(
+        3, 5, &secret, None, &mut rng,
+    )
+    .unwrap();
+    assert_eq!(dealing.shares().len(), 5);
+    assert_eq!(dealing.verifiers().len(), 3);
+    assert_eq!(
+        dealing.generator(),
+        ValueGroup::<ProjectivePoint>::generator()
+    );
+    assert!(dealing.verify_all().is_ok());
+    assert_eq!((&dealing.shares()[1..4]).combine().unwrap(), secret);
+    assert_eq!(
+        dealing.verifiers()[0],
+        ValueGroup::<ProjectivePoint>::generator() * secret
+    );
+
+    let (mut shares, verifier_set) = dealing.clone().into_parts();
+    *shares[2].1 += Scalar::ONE;
+    let corrupt = feldman::Dealing {
+        shares,
+        verifier_set,
+    };
+    assert_eq!(
+        corrupt.verify_all().unwrap_err(),
+        Error::ShareAtIndexInvalid(2)
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn shamir_combine_shares() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let shares = shamir::split_secret::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+    assert_eq!(shamir::combine_shares(&shares[1..4]).unwrap(), secret);
+    assert_eq!(shamir::combine_shares(&shares).unwrap(), secret);
+
+    assert_eq!(
+        shamir::combine_shares(&shares[..1]).unwrap_err(),
+        Error::SharingMinThreshold
+    );
+    assert_eq!(
+        shamir::combine_shares::<TestShare<Scalar>>(&[]).unwrap_err(),
+        Error::SharingMinThreshold
+    );
+    // Below the threshold still interpolates but not to the secret
+    assert_ne!(shamir::combine_shares(&shares[..2]).unwrap(), secret);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn polynomial_evaluate_at() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let (shares, polynomial) =
+        shamir::split_secret_with_polynomial::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+    assert_eq!(polynomial.len(), 3);
+    assert_eq!(polynomial.evaluate_at(&IdentifierPrimeField::ZERO), secret);
+    for (i, share) in shares.iter().enumerate() {
+        let x = IdentifierPrimeField(Scalar::from(i as u64 + 1));
+        assert_eq!(share.0, x);
+        assert_eq!(polynomial.evaluate_at(&x), share.1);
+    }
+
+    // Unused coefficients of a fixed size polynomial are ignored
+    let mut fixed = <[TestShare<Scalar>; 5] as Polynomial<_>>::create(3);
+    fixed[..3].copy_from_slice(&polynomial);
+    let x = IdentifierPrimeField(Scalar::from(9u64));
+    assert_eq!(fixed.evaluate_at(&x), polynomial.evaluate(&x, 3));
+    assert_eq!(
+        (&shares[..3]).combine_to_point(&x).unwrap(),
+        fixed.evaluate_at(&x)
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn polynomial_commit_dual() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let g = ValueGroup::<ProjectivePoint>::generator();
+    let h = ValueGroup::<ProjectivePoint>::random(&mut rng);
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let blinder = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let mut secret_polynomial = <Vec<TestShare<Scalar>> as Polynomial<_>>::create(3);
+    let mut blinder_polynomial = <Vec<TestShare<Scalar>> as Polynomial<_>>::create(3);
+    secret_polynomial.fill(&secret, &mut rng, 3).unwrap();
+    blinder_polynomial.fill(&blinder, &mut rng, 3).unwrap();
+
+    let (feldman_set, pedersen_set): (
+        Vec<ValueGroup<ProjectivePoint>>,
+        Vec<ValueGroup<ProjectivePoint>>,
+    ) = secret_polynomial
+        .commit_dual(g, h, &blinder_polynomial, 3)
+        .unwrap();
+
+    // Separate loops over each polynomial
+    let mut feldman = vec![g * secret_polynomial[0].1];
+    feldman.extend(secret_polynomial[1..].iter().map(|c| g * c.0));
+    let mut blinding = vec![h * blinder_polynomial[0].1];
+    blinding.extend(blinder_polynomial[1..].iter().map(|c| h * c.0));
+    assert_eq!(
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::generator(&feldman_set),
+        g
+    );
+    assert_eq!(
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::verifiers(&feldman_set),
+        &feldman[..]
+    );
+    assert_eq!(
+        PedersenVerifierSet::<TestShare<Scalar>, _>::secret_generator(&pedersen_set),
+        g
+    );
+    assert_eq!(
+        PedersenVerifierSet::<TestShare<Scalar>, _>::blinder_generator(&pedersen_set),
+        h
+    );
+    for ((p, f), b) in PedersenVerifierSet::<TestShare<Scalar>, _>::blind_verifiers(&pedersen_set)
+        .iter()
+        .zip(feldman.iter())
+        .zip(blinding.iter())
+    {
+        assert_eq!(*p, *f + *b);
+    }
+
+    assert_eq!(
+        secret_polynomial
+            .commit_dual::<_, Vec<ValueGroup<ProjectivePoint>>, Vec<ValueGroup<ProjectivePoint>>>(
+                g,
+                h,
+                &blinder_polynomial,
+                4
+            )
+            .unwrap_err(),
+        Error::InvalidSizeRequest
+    );
+}
+
+#[test]
+fn share_from_bytes() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let share = TestShare::<Scalar>::with_identifier_and_value(
+        IdentifierPrimeField::from(Scalar::from(7u64)),
+        IdentifierPrimeField::random(&mut rng),
+    );
+    let id_bytes = share.identifier().serialize();
+    let value_bytes = share.value().serialize();
+    assert_eq!(
+        TestShare::<Scalar>::from_bytes(&id_bytes, &value_bytes).unwrap(),
+        share
+    );
+    let default_share =
+        DefaultShare::<IdentifierPrimeField<Scalar>, IdentifierPrimeField<Scalar>>::from_bytes(
+            &id_bytes,
+            &value_bytes,
+        )
+        .unwrap();
+    assert_eq!(default_share.identifier, share.0);
+    assert_eq!(default_share.value, share.1);
+
+    // Larger than the modulus
+    let all_ones = [0xFFu8; 32];
+    assert_eq!(
+        TestShare::<Scalar>::from_bytes(&id_bytes, &all_ones).unwrap_err(),
+        Error::InvalidShareElement
+    );
+    assert_eq!(
+        TestShare::<Scalar>::from_bytes(&all_ones, &value_bytes).unwrap_err(),
+        Error::InvalidShareElement
+    );
+    assert_eq!(
+        TestShare::<Scalar>::from_bytes(&id_bytes, &value_bytes[..31]).unwrap_err(),
+        Error::InvalidShareElement
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn recover_public_share_at() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let shares = shamir::split_secret::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+    let generator = ValueGroup::<ProjectivePoint>::generator();
+    let points = shares[..3]
+        .iter()
+        .map(|s| (s.0, generator * s.1))
+        .collect::<Vec<_>>();
+
+    for share in &shares[3..] {
+        let public_share =
+            feldman::recover_public_share_at::<TestShare<Scalar>, _>(&points, generator, &share.0)
+                .unwrap();
+        assert_eq!(public_share, generator * share.1);
+    }
+    // Recovering an existing participant returns their own point
+    assert_eq!(
+        feldman::recover_public_share_at::<TestShare<Scalar>, _>(&points, generator, &shares[1].0)
+            .unwrap(),
+        points[1].1
+    );
+    assert_eq!(
+        feldman::recover_public_share_at::<TestShare<Scalar>, _>(
+            &points,
+            generator,
+            &IdentifierPrimeField::ZERO
+        )
+        .unwrap_err(),
+        Error::SharingInvalidIdentifier
+    );
+    assert_eq!(
+        feldman::recover_public_share_at::<TestShare<Scalar>, _>(
+            &points[..1],
+            generator,
+            &shares[4].0
+        )
+        .unwrap_err(),
+        Error::SharingMinThreshold
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn feldman_public_key() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::from(Scalar::from(42u64));
+    let (_, verifiers) = feldman::split_secret::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>(
+        3, 5, &secret, None, &mut rng,
+    )
+    .unwrap();
+    let public_key = FeldmanVerifierSet::<TestShare<Scalar>, _>::public_key(&verifiers).unwrap();
+    let generator = FeldmanVerifierSet::<TestShare<Scalar>, _>::generator(&verifiers);
+    assert_eq!(generator, ValueGroup::generator());
+    assert_eq!(public_key, generator * secret);
+    assert_eq!(
+        public_key.0,
+        ProjectivePoint::GENERATOR * Scalar::from(42u64)
+    );
+    assert_ne!(public_key, generator);
+
+    let custom = ValueGroup(ProjectivePoint::GENERATOR * Scalar::from(7u64));
+    let (_, verifiers) = feldman::split_secret::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>(
+        3,
+        5,
+        &secret,
+        Some(custom),
+        &mut rng,
+    )
+    .unwrap();
+    assert_eq!(
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::public_key(&verifiers).unwrap(),
+        custom * secret
+    );
+
+    let empty = <Vec<ValueGroup<ProjectivePoint>> as FeldmanVerifierSet<
+        TestShare<Scalar>,
+        _,
+    >>::empty_feldman_set_with_capacity(0, ValueGroup::generator());
+    assert_eq!(
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::public_key(&empty).unwrap_err(),
+        Error::InvalidGenerator("verifier set is empty")
+    );
+    let empty = Vec::<ValueGroup<ProjectivePoint>>::new();
+    assert_eq!(
+        FeldmanVerifierSet::<TestShare<Scalar>, _>::public_key(&empty).unwrap_err(),
+        Error::InvalidGenerator("verifier set is empty")
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn feldman_verifier_set_builder() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    let (shares, verifiers) =
+        feldman::split_secret::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>(
+            3, 5, &secret, None, &mut rng,
+        )
+        .unwrap();
+
+    let mut builder =
+        FeldmanVerifierSetBuilder::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>::new(3);
+    for commitment in FeldmanVerifierSet::<TestShare<Scalar>, _>::verifiers(&verifiers) {
+        builder.push_commitment(*commitment);
+    }
+    assert_eq!(builder.commitments().len(), 3);
+    let generator = FeldmanVerifierSet::<TestShare<Scalar>, _>::generator(&verifiers);
+    let built: Vec<ValueGroup<ProjectivePoint>> = builder.clone().finish(generator).unwrap();
+    assert_eq!(built, verifiers);
+    let built_array: [ValueGroup<ProjectivePoint>; 4] = builder.finish(generator).unwrap();
+    for share in &shares {
+        assert!(built.verify_share(share).is_ok());
+        assert!(built_array.verify_share(share).is_ok());
+    }
+
+    let mut builder =
+        FeldmanVerifierSetBuilder::<TestShare<Scalar>, ValueGroup<ProjectivePoint>>::new(3);
+    builder.push_commitment(ValueGroup(ProjectivePoint::GENERATOR));
+    let res = builder.finish::<Vec<ValueGroup<ProjectivePoint>>>(generator);
+    assert_eq!(
+        res.unwrap_err(),
+        Error::InvalidCommitmentCount {
+            expected: 3,
+            actual: 1
+        }
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn combine_share_limit() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    // Distinct identifiers so only the limit stops the quadratic work
+    let shares = (1..=DEFAULT_MAX_COMBINE_SHARES as u64 + 1)
+        .map(|i| {
+            (
+                IdentifierPrimeField::from(Scalar::from(i)),
+                IdentifierPrimeField::from(Scalar::ONE),
+            )
+        })
+        .collect::<Vec<TestShare<Scalar>>>();
+    assert_eq!(shares.combine().unwrap_err(), Error::InvalidSizeRequest);
+
+    // The limit is checked before the identifiers are scanned,
+    // so oversized sets are rejected without any per share work
+    let shares = vec![
+        (
+            IdentifierPrimeField::from(Scalar::ONE),
+            IdentifierPrimeField::from(Scalar::ONE),
+        );
+        DEFAULT_MAX_COMBINE_SHARES + 1
+    ];
+    assert_eq!(shares.combine().unwrap_err(), Error::InvalidSizeRequest);
+    assert_eq!(
+        (&shares[..DEFAULT_MAX_COMBINE_SHARES])
+            .combine()
+            .unwrap_err(),
+        Error::SharingDuplicateIdentifier
+    );
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    let shares = shamir::split_secret::<TestShare<Scalar>>(3, 5, &secret, &mut rng).unwrap();
+    assert_eq!(
+        shares.combine_with_limit(4).unwrap_err(),
+        Error::InvalidSizeRequest
+    );
+    assert_eq!((&shares[..4]).combine_with_limit(4).unwrap(), secret);
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn reconstruct_verifiers_from_public_shares() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    type K256Verifier = ValueGroup<ProjectivePoint>;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    let (shares, verifiers) =
+        feldman::split_secret::<TestShare<Scalar>, K256Verifier>(3, 5, &secret, None, &mut rng)
+            .unwrap();
+    let generator = FeldmanVerifierSet::<TestShare<Scalar>, _>::generator(&verifiers);
+
+    let points = shares[1..4]
+        .iter()
+        .map(|s| (*s.identifier(), generator * s.value()))
+        .collect::<Vec<_>>();
+    let reconstructed = <Vec<K256Verifier> as FeldmanVerifierSet<
+        TestShare<Scalar>,
+        K256Verifier,
+    >>::reconstruct_from_public_shares(generator, &points)
+    .unwrap();
+    assert_eq!(reconstructed, verifiers);
+    for share in &shares {
+        assert!(reconstructed.verify_share(share).is_ok());
+    }
+
+    let duplicate = [points[0], points[1], points[0]];
+    let res =
+        <Vec<K256Verifier> as FeldmanVerifierSet<TestShare<Scalar>, K256Verifier>>::reconstruct_from_public_shares(
+            generator, &duplicate,
+        );
+    assert_eq!(res.unwrap_err(), Error::SharingDuplicateIdentifier);
+}
+
 #[cfg(all(feature = "serde", any(feature = "alloc", feature = "std")))]
 #[test]
 fn share_binary_serde() {
@@ -97,3 +2395,215 @@ fn share_binary_serde() {
     let sk5 = res.unwrap();
     assert_eq!(sk, sk5);
 }
+
+#[cfg(all(feature = "arbitrary", any(feature = "alloc", feature = "std")))]
+#[test]
+fn arbitrary_inputs_do_not_panic() {
+    use arbitrary::{Arbitrary, Unstructured};
+    use rand_chacha::ChaCha8Rng;
+    use rand_core::{RngCore, SeedableRng};
+
+    type K256Share = DefaultShare<IdentifierPrimeField<Scalar>, IdentifierPrimeField<Scalar>>;
+    type K256Verifier = ValueGroup<ProjectivePoint>;
+
+    let mut rng = ChaCha8Rng::from_seed([9u8; 32]);
+    let mut data = vec![0u8; 4096];
+    for _ in 0..32 {
+        rng.fill_bytes(&mut data);
+        let mut u = Unstructured::new(&data);
+
+        let shares = Vec::<K256Share>::arbitrary(&mut u).unwrap();
+        let _ = shares.combine();
+
+        let feldman = VecFeldmanVerifierSet::<K256Share, K256Verifier>::arbitrary(&mut u).unwrap();
+        let pedersen =
+            VecPedersenVerifierSet::<K256Share, K256Verifier>::arbitrary(&mut u).unwrap();
+        for share in &shares {
+            let _ = feldman.verify_share(share);
+            let _ = pedersen.verify_share_and_blinder(share, share);
+        }
+    }
+
+    // Empty input produces empty sets which must error instead of panicking
+    let mut u = Unstructured::new(&[]);
+    let feldman = VecFeldmanVerifierSet::<K256Share, K256Verifier>::arbitrary(&mut u).unwrap();
+    let pedersen = VecPedersenVerifierSet::<K256Share, K256Verifier>::arbitrary(&mut u).unwrap();
+    assert!(feldman.is_empty());
+    assert!(pedersen.is_empty());
+    let share = K256Share::with_identifier_and_value(
+        IdentifierPrimeField(Scalar::ONE),
+        IdentifierPrimeField(Scalar::ONE),
+    );
+    assert!(feldman.verify_share(&share).is_err());
+    assert!(pedersen.verify_share_and_blinder(&share, &share).is_err());
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn tracing_spans() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+    use std::{
+        string::{String, ToString},
+        sync::{Arc, Mutex},
+    };
+    use tracing::{
+        field::{Field as TracingField, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    type Recorded = Arc<Mutex<Vec<(String, Vec<(String, String)>)>>>;
+
+    struct Recorder(Recorded);
+
+    struct FieldRecorder<'a>(&'a mut Vec<(String, String)>);
+
+    impl Visit for FieldRecorder<'_> {
+        fn record_str(&mut self, field: &TracingField, value: &str) {
+            self.0.push((field.name().to_string(), value.to_string()));
+        }
+
+        fn record_debug(&mut self, field: &TracingField, value: &dyn Debug) {
+            self.0
+                .push((field.name().to_string(), format!("{:?}", value)));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &span::Attributes<'_>) -> span::Id {
+            let mut fields = Vec::new();
+            attrs.record(&mut FieldRecorder(&mut fields));
+            let mut spans = self.0.lock().unwrap();
+            spans.push((attrs.metadata().name().to_string(), fields));
+            span::Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, _span: &span::Id) {}
+
+        fn exit(&self, _span: &span::Id) {}
+    }
+
+    let recorded = Recorded::default();
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::from(Scalar::random(&mut rng));
+    tracing::subscriber::with_default(Recorder(recorded.clone()), || {
+        let (shares, verifiers) = feldman::split_secret::<
+            TestShare<Scalar>,
+            ValueGroup<ProjectivePoint>,
+        >(2, 3, &secret, None, &mut rng)
+        .unwrap();
+        assert!(verifiers.verify_share(&shares[0]).is_ok());
+        assert_eq!((&shares[..2]).combine().unwrap(), secret);
+    });
+
+    let field = |name: &str, value: &str| (name.to_string(), value.to_string());
+    let spans = recorded.lock().unwrap();
+    assert_eq!(
+        *spans,
+        vec![
+            (
+                "split_secret".to_string(),
+                vec![
+                    field("scheme", "feldman"),
+                    field("threshold", "2"),
+                    field("limit", "3"),
+                ]
+            ),
+            (
+                "verify_share".to_string(),
+                vec![field("scheme", "feldman"), field("threshold", "2")]
+            ),
+            ("combine".to_string(), vec![field("share_count", "2")]),
+        ]
+    );
+    let secret_debug = format!("{:?}", secret.0);
+    for (_, fields) in spans.iter() {
+        for (_, value) in fields {
+            assert!(!value.contains(&secret_debug));
+        }
+    }
+}
+
+#[cfg(any(feature = "json", feature = "cbor"))]
+#[test]
+fn share_envelope() {
+    use crate::tests::utils::MockRng;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField::<Scalar>::random(&mut rng);
+    let shares = shamir::split_secret::<TestShare<Scalar>>(2, 3, &secret, &mut rng).unwrap();
+    let envelopes = shares
+        .iter()
+        .map(|s| ShareEnvelope::new("k256-shamir", s))
+        .collect::<Vec<_>>();
+
+    #[cfg(feature = "json")]
+    {
+        let decoded = envelopes
+            .iter()
+            .map(|e| {
+                let e = ShareEnvelope::from_json(&e.to_json().unwrap()).unwrap();
+                assert_eq!(e.scheme, "k256-shamir");
+                e.to_share::<TestShare<Scalar>>().unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(decoded, shares);
+        assert_eq!(decoded.combine().unwrap(), secret);
+    }
+    #[cfg(feature = "cbor")]
+    {
+        let decoded = envelopes
+            .iter()
+            .map(|e| {
+                let bytes = e.to_cbor().unwrap();
+                let e2 = ShareEnvelope::from_cbor(&bytes).unwrap();
+                assert_eq!(&e2, e);
+                assert_eq!(e2.to_cbor().unwrap(), bytes);
+                e2.to_share::<TestShare<Scalar>>().unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(decoded, shares);
+        assert_eq!(decoded.combine().unwrap(), secret);
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn test_vectors() {
+    let (secret, shares) = test_vectors::generate::<Scalar>(b"vsss-rs", 2, 3).unwrap();
+    assert_eq!(
+        hex::encode(&secret),
+        "c37cb40f5e08af26450c4567a68c95fd185a09f44e4918482735e6599fab5c41"
+    );
+    let expected = [
+        "0000000000000000000000000000000000000000000000000000000000000001a29bfdf6f1f26daddde0bdd96db6e5356fcf348407bc2642eb11eff8ba5757bc",
+        "000000000000000000000000000000000000000000000000000000000000000281bb47de85dc2c3576b5364b34e1346dc7445f13c12f343daeedf997d5035337",
+        "000000000000000000000000000000000000000000000000000000000000000360da91c619c5eabd0f89aebcfc0b83a61eb989a37aa2423872ca0336efaf4eb2",
+    ];
+    assert_eq!(shares.iter().map(hex::encode).collect::<Vec<_>>(), expected);
+
+    let decoded = shares
+        .iter()
+        .map(|s| {
+            (
+                IdentifierPrimeField::<Scalar>::from_slice(&s[..32]).unwrap(),
+                IdentifierPrimeField::<Scalar>::from_slice(&s[32..]).unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!((&decoded[1..]).combine().unwrap().to_vec(), secret);
+    assert_eq!(
+        test_vectors::generate::<Scalar>(b"vsss-rs", 2, 3).unwrap(),
+        (secret, shares)
+    );
+}
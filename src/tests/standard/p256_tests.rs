@@ -48,3 +48,155 @@ fn key_tests() {
     let sk_dup = SecretKey::from(nzs_dup);
     assert_eq!(sk_dup.to_bytes(), sk.to_bytes());
 }
+
+#[cfg(all(feature = "sec1", any(feature = "alloc", feature = "std")))]
+#[test]
+fn sec1_compressed_verifier_set() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::sec1::ToEncodedPoint;
+    use p256::NistP256;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField(Scalar::from(42u64));
+    let (shares, verifiers) = feldman::split_secret::<
+        TestShare<Scalar>,
+        ShareVerifierGroup<ProjectivePoint>,
+    >(3, 5, &secret, None, &mut rng)
+    .unwrap();
+
+    let encoded = verifiers
+        .iter()
+        .map(|v| v.to_sec1_compressed::<NistP256>())
+        .collect::<VsssResult<Vec<_>>>()
+        .unwrap();
+    for e in &encoded {
+        assert_eq!(e.len(), 33);
+    }
+    let decoded = encoded
+        .iter()
+        .map(|e| ShareVerifierGroup::<ProjectivePoint>::from_sec1_compressed::<NistP256>(e))
+        .collect::<VsssResult<Vec<_>>>()
+        .unwrap();
+    assert_eq!(decoded, verifiers);
+    for s in &shares {
+        assert!(decoded.verify_share(s).is_ok());
+    }
+
+    let uncompressed = verifiers[1].0.to_encoded_point(false);
+    assert_eq!(
+        ShareVerifierGroup::<ProjectivePoint>::from_sec1_compressed::<NistP256>(
+            uncompressed.as_bytes()
+        )
+        .unwrap_err(),
+        Error::InvalidShareElement
+    );
+    assert_eq!(
+        ShareVerifierGroup::<ProjectivePoint>::from_sec1_compressed::<NistP256>(&encoded[0][..32])
+            .unwrap_err(),
+        Error::InvalidShareElement
+    );
+    assert_eq!(
+        ValueGroup(ProjectivePoint::IDENTITY)
+            .to_sec1_compressed::<NistP256>()
+            .unwrap_err(),
+        Error::InvalidShareElement
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn additive() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    let secret = IdentifierPrimeField(Scalar::random(&mut rng));
+    let shares = additive::split::<TestShare<Scalar>>(5, &secret, &mut rng).unwrap();
+    assert_eq!(shares.len(), 5);
+    for (i, share) in shares.iter().enumerate() {
+        assert_eq!(share.0, IdentifierPrimeField(Scalar::from(i as u64 + 1)));
+    }
+    assert_eq!(additive::combine(&shares).unwrap(), secret);
+
+    for i in 0..shares.len() {
+        let mut missing = shares.clone();
+        missing.remove(i);
+        assert_ne!(additive::combine(&missing).unwrap(), secret);
+    }
+
+    assert_eq!(
+        additive::split::<TestShare<Scalar>>(1, &secret, &mut rng).unwrap_err(),
+        Error::SharingMinThreshold
+    );
+    let duplicate = [shares[0], shares[0]];
+    assert_eq!(
+        additive::combine(&duplicate).unwrap_err(),
+        Error::SharingDuplicateIdentifier
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn additive_combine_sharded() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    let master = IdentifierPrimeField(Scalar::random(&mut rng));
+    let sub_secrets = additive::split::<TestShare<Scalar>>(2, &master, &mut rng).unwrap();
+    let sub_shares = sub_secrets
+        .iter()
+        .map(|s| shamir::split_secret::<TestShare<Scalar>>(3, 5, &s.1, &mut rng).unwrap())
+        .collect::<Vec<_>>();
+    let add = |a: IdentifierPrimeField<Scalar>, b: IdentifierPrimeField<Scalar>| {
+        IdentifierPrimeField(a.0 + b.0)
+    };
+
+    let quorums = [&sub_shares[0][..3], &sub_shares[1][2..]];
+    assert_eq!(additive::combine_sharded(&quorums, add).unwrap(), master);
+    // Only one sub-secret
+    assert_ne!(
+        additive::combine_sharded(&quorums[..1], add).unwrap(),
+        master
+    );
+
+    let short = [&sub_shares[0][..3], &sub_shares[1][..1]];
+    assert_eq!(
+        additive::combine_sharded(&short, add).unwrap_err(),
+        Error::SharingMinThreshold
+    );
+    assert_eq!(
+        additive::combine_sharded::<TestShare<Scalar>, _>(&[], add).unwrap_err(),
+        Error::InvalidSizeRequest
+    );
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[test]
+fn split_secrets() {
+    use crate::tests::utils::MockRng;
+    use elliptic_curve::Field;
+
+    let mut rng = MockRng::default();
+    let secrets = [
+        IdentifierPrimeField(Scalar::random(&mut rng)),
+        IdentifierPrimeField(Scalar::random(&mut rng)),
+        IdentifierPrimeField(Scalar::random(&mut rng)),
+    ];
+    let share_sets = shamir::split_secrets::<TestShare<Scalar>>(2, 3, &secrets, &mut rng).unwrap();
+    assert_eq!(share_sets.len(), 3);
+    for (shares, secret) in share_sets.iter().zip(&secrets) {
+        assert_eq!(shares.len(), 3);
+        for (share, first) in shares.iter().zip(&share_sets[0]) {
+            assert_eq!(share.0, first.0);
+        }
+        assert_eq!((&shares[..2]).combine().unwrap(), *secret);
+        assert_eq!((&shares[1..]).combine().unwrap(), *secret);
+    }
+    assert_ne!(share_sets[0][0].1, share_sets[1][0].1);
+
+    assert_eq!(
+        shamir::split_secrets::<TestShare<Scalar>>(2, 3, &[], &mut rng).unwrap_err(),
+        Error::InvalidSizeRequest
+    );
+}
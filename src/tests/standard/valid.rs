@@ -227,6 +227,7 @@ fn pedersen_split<G: Group + GroupEncoding + Default>(
         secret_generator: None,
         blinder_generator: None,
         participant_generators: &[numbering],
+        reuse_feldman_commitments: None,
     };
     FixedArrayVsss8Of15::split_secret_with_blind_verifiers(threshold, limit, &options, rng)
 }
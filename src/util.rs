@@ -236,3 +236,77 @@ impl CtIsNotZero for usize {
         subtle::Choice::from(a as u8)
     }
 }
+
+/// Marks an RNG as a [`rand_core::CryptoRng`] on behalf of the caller.
+///
+/// The splitting functions require `RngCore + CryptoRng` so a
+/// cryptographically secure RNG is used for the polynomial coefficients.
+/// Some secure RNGs do not implement the [`rand_core::CryptoRng`] marker,
+/// for example ones written against a different `rand` version
+/// whose `RngCore` has been bridged to `rand_core` 0.6.
+/// Wrapping such an RNG asserts that it is secure.
+///
+/// The wrapper does not make an RNG secure. Wrapping a predictable RNG
+/// like a seeded xorshift produces shares that reveal the secret.
+/// Only use this with RNGs that are known to be cryptographically secure.
+#[derive(Debug, Clone, Default)]
+#[repr(transparent)]
+pub struct AssertCryptoRng<R: rand_core::RngCore>(pub R);
+
+impl<R: rand_core::RngCore> rand_core::RngCore for AssertCryptoRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+impl<R: rand_core::RngCore> rand_core::CryptoRng for AssertCryptoRng<R> {}
+
+/// Overwrites the wrapped value with its default when dropped.
+///
+/// A `no_std` stand-in for `zeroize::Zeroizing` for generic share elements
+/// that aren't known to implement `Zeroize`. The default of a share element
+/// is zero so this clears field elements held inline. Values owning heap
+/// buffers are dropped before being overwritten so those buffers are not
+/// cleared, and copies the compiler made elsewhere are out of reach.
+pub(crate) struct Scrub<T: Default>(pub(crate) T);
+
+impl<T: Default> core::ops::Deref for Scrub<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Default> core::ops::DerefMut for Scrub<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Default> Drop for Scrub<T> {
+    fn drop(&mut self) {
+        drop(core::mem::take(&mut self.0));
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+        #[cfg(all(test, feature = "std"))]
+        SCRUBBED.with(|count| count.set(count.get() + 1));
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+std::thread_local! {
+    /// The number of values this thread has scrubbed
+    pub(crate) static SCRUBBED: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+}